@@ -1,20 +1,33 @@
 use {
     crate::{
         commands::CommandExec,
+        config::expand_tilde,
+        constants::{WATCH_CONNECT_TIMEOUT_SECS, WATCH_POLL_INTERVAL_MS},
         context::ScillaContext,
         error::ScillaResult,
-        misc::helpers::{bincode_deserialize, lamports_to_sol},
-        prompt::prompt_data,
+        misc::{
+            accounts::{account_changes, decode_account, hex_dump},
+            helpers::{PubkeyList, bincode_deserialize, lamports_to_sol, websocket_url},
+            programs::known_program_name,
+        },
+        prompt::{prompt_data, prompt_optional_data},
         ui::{print_error, show_spinner},
     },
     anyhow::bail,
+    base64::Engine,
     comfy_table::{Cell, Table, presets::UTF8_FULL},
     console::style,
+    futures::{StreamExt, stream},
     inquire::Select,
+    solana_account::Account,
+    solana_account_decoder_client_types::UiAccountEncoding,
     solana_nonce::versions::Versions,
     solana_pubkey::Pubkey,
-    solana_rpc_client_api::config::{RpcLargestAccountsConfig, RpcLargestAccountsFilter},
-    std::fmt,
+    solana_pubsub_client::nonblocking::pubsub_client::PubsubClient,
+    solana_rpc_client_api::config::{
+        RpcAccountInfoConfig, RpcLargestAccountsConfig, RpcLargestAccountsFilter,
+    },
+    std::{collections::BTreeMap, fmt, fs, pin::Pin, time::Duration},
 };
 
 /// Commands related to wallet or account management
@@ -26,6 +39,7 @@ pub enum AccountCommand {
     Airdrop,
     LargestAccounts,
     NonceAccount,
+    Watch,
     GoBack,
 }
 
@@ -38,6 +52,7 @@ impl AccountCommand {
             AccountCommand::Airdrop => "Requesting SOL on devnet/testnet…",
             AccountCommand::LargestAccounts => "Fetching largest accounts on the cluster…",
             AccountCommand::NonceAccount => "Inspecting or managing durable nonces…",
+            AccountCommand::Watch => "Fetching accounts to watch…",
             AccountCommand::GoBack => "Going back…",
         }
     }
@@ -52,6 +67,7 @@ impl fmt::Display for AccountCommand {
             AccountCommand::Airdrop => "Request airdrop",
             AccountCommand::LargestAccounts => "View largest accounts",
             AccountCommand::NonceAccount => "View nonce account",
+            AccountCommand::Watch => "Watch accounts",
             AccountCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -63,7 +79,11 @@ impl AccountCommand {
         match self {
             AccountCommand::FetchAccount => {
                 let pubkey: Pubkey = prompt_data("Enter Pubkey:")?;
-                show_spinner(self.spinner_msg(), fetch_acc_data(ctx, &pubkey)).await?;
+                let raw_data =
+                    show_spinner(self.spinner_msg(), fetch_acc_data(ctx, &pubkey)).await?;
+                if let Some(data) = raw_data {
+                    prompt_raw_data(&pubkey, &data)?;
+                }
             }
             AccountCommand::Balance => {
                 let pubkey: Pubkey = prompt_data("Enter Pubkey :")?;
@@ -82,6 +102,16 @@ impl AccountCommand {
                 let pubkey: Pubkey = prompt_data("Enter nonce account pubkey:")?;
                 show_spinner(self.spinner_msg(), fetch_nonce_account(ctx, &pubkey)).await?;
             }
+            AccountCommand::Watch => {
+                let pubkeys: PubkeyList =
+                    prompt_data("Enter accounts to watch (comma or space separated):")?;
+                let snapshots = show_spinner(
+                    self.spinner_msg(),
+                    fetch_watch_snapshots(ctx, pubkeys.pubkeys()),
+                )
+                .await?;
+                watch_accounts(ctx, snapshots).await?;
+            }
             AccountCommand::GoBack => {
                 return Ok(CommandExec::GoBack);
             }
@@ -109,9 +139,16 @@ async fn request_sol_airdrop(ctx: &ScillaContext) -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
+/// Prints the account and its decoded data. Returns the raw data when its
+/// layout isn't one Scilla can decode.
+async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<Option<Vec<u8>>> {
     let acc = ctx.rpc().get_account(pubkey).await?;
 
+    let owner = match known_program_name(&acc.owner) {
+        Some(name) => format!("{} ({name})", acc.owner),
+        None => acc.owner.to_string(),
+    };
+
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
@@ -124,13 +161,14 @@ async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<
             Cell::new(format!("{}", acc.lamports)),
         ])
         .add_row(vec![
-            Cell::new("Data Length"),
-            Cell::new(format!("{}", acc.data.len())),
+            Cell::new("Balance (SOL)"),
+            Cell::new(format!("{}", lamports_to_sol(acc.lamports))),
         ])
         .add_row(vec![
-            Cell::new("Owner"),
-            Cell::new(format!("{}", acc.owner)),
+            Cell::new("Data Length"),
+            Cell::new(format!("{}", acc.data.len())),
         ])
+        .add_row(vec![Cell::new("Owner"), Cell::new(owner)])
         .add_row(vec![
             Cell::new("Executable"),
             Cell::new(format!("{}", acc.executable)),
@@ -142,6 +180,71 @@ async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<
 
     println!("{}\n{}", style("ACCOUNT INFO").green().bold(), table);
 
+    let Some(decoded) = decode_account(pubkey, &acc) else {
+        if acc.data.is_empty() {
+            return Ok(None);
+        }
+        println!(
+            "{}",
+            style("Account data doesn't match a layout Scilla knows").yellow()
+        );
+        return Ok(Some(acc.data));
+    };
+
+    if !decoded.fields.is_empty() {
+        let mut decoded_table = Table::new();
+        decoded_table
+            .load_preset(UTF8_FULL)
+            .set_header(vec![
+                Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+            ])
+            .add_rows(
+                decoded
+                    .fields
+                    .iter()
+                    .map(|(label, value)| vec![Cell::new(label), Cell::new(value)]),
+            );
+        println!(
+            "\n{}\n{}",
+            style(decoded.kind.to_uppercase()).green().bold(),
+            decoded_table
+        );
+    } else {
+        println!("\n{}", style(decoded.kind.to_uppercase()).green().bold());
+    }
+
+    Ok(None)
+}
+
+/// Offers the raw data of an undecoded account as a hex dump or a base64 file
+fn prompt_raw_data(pubkey: &Pubkey, data: &[u8]) -> anyhow::Result<()> {
+    let choice = Select::new(
+        "Raw account data:",
+        vec!["Skip", "Show hex dump", "Export as base64"],
+    )
+    .prompt()?;
+
+    match choice {
+        "Show hex dump" => println!("{}", hex_dump(data)),
+        "Export as base64" => {
+            let path: Option<String> = prompt_optional_data(&format!(
+                "Enter output file path (leave empty for {pubkey}.b64):"
+            ))?;
+            let path = expand_tilde(&path.unwrap_or_else(|| format!("{pubkey}.b64")));
+            fs::write(
+                &path,
+                base64::engine::general_purpose::STANDARD.encode(data),
+            )?;
+            println!(
+                "{} {}",
+                style("Account data written to").green().bold(),
+                style(path.display()).cyan()
+            );
+        }
+        _ => {}
+    }
+
     Ok(())
 }
 
@@ -247,3 +350,229 @@ async fn fetch_nonce_account(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Re
 
     Ok(())
 }
+
+/// Latest known state of each watched account; `None` while it does not exist
+type WatchSnapshots = BTreeMap<Pubkey, Option<Account>>;
+
+/// Current state of `pubkeys` and the slot it was read at. Accounts drained
+/// to zero lamports count as closed.
+async fn fetch_account_states(
+    ctx: &ScillaContext,
+    pubkeys: &[Pubkey],
+) -> anyhow::Result<(u64, Vec<Option<Account>>)> {
+    let mut slot = 0;
+    let mut accounts = Vec::with_capacity(pubkeys.len());
+    // getMultipleAccounts accepts at most 100 keys per request
+    for chunk in pubkeys.chunks(100) {
+        let response = ctx
+            .rpc()
+            .get_multiple_accounts_with_commitment(chunk, ctx.rpc().commitment())
+            .await?;
+        slot = slot.max(response.context.slot);
+        accounts.extend(
+            response
+                .value
+                .into_iter()
+                .map(|account| account.filter(|account| account.lamports > 0)),
+        );
+    }
+    Ok((slot, accounts))
+}
+
+async fn fetch_watch_snapshots(
+    ctx: &ScillaContext,
+    pubkeys: &[Pubkey],
+) -> anyhow::Result<WatchSnapshots> {
+    let (_, accounts) = fetch_account_states(ctx, pubkeys).await?;
+    Ok(pubkeys.iter().copied().zip(accounts).collect())
+}
+
+/// Stores the new state of `pubkey` and prints a timestamped line with what
+/// changed, if anything
+fn record_account_update(
+    snapshots: &mut WatchSnapshots,
+    pubkey: &Pubkey,
+    slot: u64,
+    account: Option<Account>,
+) {
+    let previous = snapshots.insert(*pubkey, account).flatten();
+    let changes = account_changes(pubkey, previous.as_ref(), snapshots[pubkey].as_ref());
+    if changes.is_empty() {
+        return;
+    }
+
+    println!(
+        "{} {} {}",
+        style(chrono::Local::now().format("%H:%M:%S")).dim(),
+        style(pubkey).cyan(),
+        style(format!("(slot {slot})")).dim()
+    );
+    for change in changes {
+        println!(
+            "    {}: {} → {}",
+            style(change.field).bold(),
+            change.before,
+            style(change.after).green()
+        );
+    }
+}
+
+/// Prints the starting state, then every change until Ctrl+C. Websocket
+/// notifications are used when the RPC node serves them, polling otherwise.
+async fn watch_accounts(ctx: &ScillaContext, mut snapshots: WatchSnapshots) -> anyhow::Result<()> {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Owner").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Data Length").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (pubkey, account) in &snapshots {
+        match account {
+            Some(account) => table.add_row(vec![
+                Cell::new(pubkey),
+                Cell::new(lamports_to_sol(account.lamports)),
+                Cell::new(
+                    known_program_name(&account.owner)
+                        .map_or_else(|| account.owner.to_string(), str::to_string),
+                ),
+                Cell::new(account.data.len()),
+            ]),
+            None => table.add_row(vec![
+                Cell::new(pubkey),
+                Cell::new("-"),
+                Cell::new("Does not exist"),
+                Cell::new("-"),
+            ]),
+        };
+    }
+
+    println!("\n{}", style("WATCHING ACCOUNTS").green().bold());
+    println!("{table}");
+    println!("{}", style("Press Ctrl+C to stop").dim());
+
+    // One listener covers connecting, subscribing and both watch modes
+    let stop = tokio::signal::ctrl_c();
+    tokio::pin!(stop);
+
+    let stopped = match websocket_url(&ctx.rpc().url()) {
+        Some(url) => watch_with_websocket(ctx, &url, &mut snapshots, stop.as_mut()).await?,
+        None => false,
+    };
+    if !stopped {
+        println!(
+            "{}",
+            style(format!(
+                "Polling every {}s instead",
+                WATCH_POLL_INTERVAL_MS as f64 / 1000.0
+            ))
+            .yellow()
+        );
+        watch_with_polling(ctx, &mut snapshots, stop).await?;
+    }
+
+    println!("{}", style("Stopped watching").dim());
+    Ok(())
+}
+
+/// Follows `accountSubscribe` notifications. Returns `true` when stopped with
+/// Ctrl+C and `false` when the websocket is unavailable or closes.
+async fn watch_with_websocket(
+    ctx: &ScillaContext,
+    url: &str,
+    snapshots: &mut WatchSnapshots,
+    mut stop: Pin<&mut impl Future<Output = std::io::Result<()>>>,
+) -> anyhow::Result<bool> {
+    let connect = tokio::time::timeout(
+        Duration::from_secs(WATCH_CONNECT_TIMEOUT_SECS),
+        PubsubClient::new(url),
+    );
+    let connected = tokio::select! {
+        _ = stop.as_mut() => return Ok(true),
+        connected = connect => connected,
+    };
+    let client = match connected {
+        Ok(Ok(client)) => client,
+        Ok(Err(err)) => {
+            println!(
+                "{}",
+                style(format!("Websocket unavailable: {err}")).yellow()
+            );
+            return Ok(false);
+        }
+        Err(_) => {
+            println!("{}", style("Websocket connection timed out").yellow());
+            return Ok(false);
+        }
+    };
+
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(ctx.rpc().commitment()),
+        ..Default::default()
+    };
+    let pubkeys: Vec<Pubkey> = snapshots.keys().copied().collect();
+    let mut subscriptions = Vec::with_capacity(pubkeys.len());
+    for pubkey in pubkeys {
+        let subscribed = tokio::select! {
+            _ = stop.as_mut() => return Ok(true),
+            subscribed = client.account_subscribe(&pubkey, Some(config.clone())) => subscribed,
+        };
+        match subscribed {
+            Ok((notifications, _unsubscribe)) => {
+                subscriptions.push(notifications.map(move |response| (pubkey, response)));
+            }
+            Err(err) => {
+                println!(
+                    "{}",
+                    style(format!("Subscribing to {pubkey} failed: {err}")).yellow()
+                );
+                return Ok(false);
+            }
+        }
+    }
+    let mut notifications = stream::select_all(subscriptions);
+
+    loop {
+        tokio::select! {
+            _ = stop.as_mut() => return Ok(true),
+            notification = notifications.next() => {
+                let Some((pubkey, response)) = notification else {
+                    println!("{}", style("Websocket closed").yellow());
+                    return Ok(false);
+                };
+                let account = response
+                    .value
+                    .decode::<Account>()
+                    .filter(|account| account.lamports > 0);
+                record_account_update(snapshots, &pubkey, response.context.slot, account);
+            }
+        }
+    }
+}
+
+/// Re-reads every account on an interval until Ctrl+C
+async fn watch_with_polling(
+    ctx: &ScillaContext,
+    snapshots: &mut WatchSnapshots,
+    mut stop: Pin<&mut impl Future<Output = std::io::Result<()>>>,
+) -> anyhow::Result<()> {
+    let pubkeys: Vec<Pubkey> = snapshots.keys().copied().collect();
+
+    loop {
+        tokio::select! {
+            _ = stop.as_mut() => return Ok(()),
+            _ = tokio::time::sleep(Duration::from_millis(WATCH_POLL_INTERVAL_MS)) => {}
+        }
+
+        // A failed read is retried on the next tick rather than ending the watch
+        match fetch_account_states(ctx, &pubkeys).await {
+            Ok((slot, accounts)) => {
+                for (pubkey, account) in pubkeys.iter().zip(accounts) {
+                    record_account_update(snapshots, pubkey, slot, account);
+                }
+            }
+            Err(err) => println!("{}", style(format!("Polling failed: {err}")).yellow()),
+        }
+    }
+}
@@ -1,11 +1,52 @@
 use {
     crate::{
-        commands::CommandExec, constants::LAMPORTS_PER_SOL, context::ScillaContext,
-        error::ScillaResult, ui::show_spinner,
+        commands::{CommandExec, transaction::prompt_inspect_transaction},
+        constants::{
+            DASHBOARD_BAR_WIDTH, DASHBOARD_REFRESH_SECS, DEFAULT_REWARD_EPOCHS,
+            EPOCH_PROGRESS_BAR_WIDTH, LAMPORTS_PER_SOL, LEADER_WINDOW_LIMIT,
+            NODE_VERSION_BAR_WIDTH, PERFORMANCE_SAMPLE_LIMIT,
+        },
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::{
+            health::{EndpointReport, probe_endpoint, recommend_endpoint},
+            helpers::{
+                Commission, EpochTarget, PubkeyList, annualized_apy, average_slot_time_ms,
+                epochs_per_year, estimate_slot_datetime, fetch_average_slot_time_ms,
+                format_duration, lamports_to_sol, stake_by_version, transactions_per_second,
+                validators_to_exceed, write_csv,
+            },
+            programs::program_label,
+            validator_info::{fetch_validator_names, validator_name},
+        },
+        prompt::{prompt_data, prompt_optional_data},
+        ui::{HiddenCursor, progress_bar, show_spinner},
     },
+    anyhow::{anyhow, bail},
     comfy_table::{Cell, Table, presets::UTF8_FULL},
-    console::style,
-    std::{fmt, ops::Div},
+    console::{Term, style},
+    inquire::{Confirm, Select},
+    serde_json::json,
+    solana_epoch_info::EpochInfo,
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{
+        config::{RpcBlockConfig, RpcBlockProductionConfig, RpcLeaderScheduleConfig},
+        request::RpcRequest,
+        response::{
+            Response, RpcBlockProduction, RpcContactInfo, RpcPerfSample, RpcSupply,
+            RpcVoteAccountInfo, RpcVoteAccountStatus,
+        },
+    },
+    solana_sdk_ids::vote,
+    solana_signature::Signature,
+    solana_transaction_status::{TransactionDetails, UiTransactionEncoding},
+    std::{
+        collections::{BTreeMap, HashMap},
+        fmt,
+        ops::Div,
+        path::{Path, PathBuf},
+        time::Duration,
+    },
 };
 
 /// Commands related to cluster operations
@@ -15,24 +56,38 @@ pub enum ClusterCommand {
     CurrentSlot,
     BlockHeight,
     BlockTime,
+    Block,
     Validators,
+    ClusterNodes,
     SupplyInfo,
     Inflation,
+    InflationRewards,
+    LeaderSchedule,
+    BlockProduction,
     ClusterVersion,
+    Health,
+    Dashboard,
     GoBack,
 }
 
 impl ClusterCommand {
     pub fn spinner_msg(&self) -> &'static str {
         match self {
-            ClusterCommand::EpochInfo => "Fetching current epoch and progress…",
+            ClusterCommand::EpochInfo => "Fetching epoch progress and schedule…",
             ClusterCommand::CurrentSlot => "Fetching latest confirmed slot…",
             ClusterCommand::BlockHeight => "Fetching current block height…",
             ClusterCommand::BlockTime => "Fetching block timestamp…",
+            ClusterCommand::Block => "Fetching block…",
             ClusterCommand::Validators => "Fetching active validators…",
+            ClusterCommand::ClusterNodes => "Fetching gossip nodes…",
             ClusterCommand::ClusterVersion => "Fetching cluster Solana version…",
+            ClusterCommand::Health => "Checking RPC endpoints…",
             ClusterCommand::SupplyInfo => "Fetching total and circulating supply…",
             ClusterCommand::Inflation => "Fetching inflation parameters…",
+            ClusterCommand::InflationRewards => "Fetching inflation rewards…",
+            ClusterCommand::LeaderSchedule => "Fetching leader schedule…",
+            ClusterCommand::BlockProduction => "Fetching block production…",
+            ClusterCommand::Dashboard => "Loading validator names…",
             ClusterCommand::GoBack => "Going back…",
         }
     }
@@ -45,10 +100,17 @@ impl fmt::Display for ClusterCommand {
             ClusterCommand::CurrentSlot => "Current Slot",
             ClusterCommand::BlockHeight => "Block Height",
             ClusterCommand::BlockTime => "Block Time",
+            ClusterCommand::Block => "Fetch Block",
             ClusterCommand::Validators => "Validators",
+            ClusterCommand::ClusterNodes => "Cluster Nodes",
             ClusterCommand::ClusterVersion => "Cluster Version",
+            ClusterCommand::Health => "Cluster Health",
             ClusterCommand::SupplyInfo => "Supply Info",
             ClusterCommand::Inflation => "Inflation",
+            ClusterCommand::InflationRewards => "Inflation Rewards",
+            ClusterCommand::LeaderSchedule => "Leader Schedule",
+            ClusterCommand::BlockProduction => "Block Production",
+            ClusterCommand::Dashboard => "Live Dashboard",
             ClusterCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -58,8 +120,18 @@ impl fmt::Display for ClusterCommand {
 impl ClusterCommand {
     pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
         match self {
+            ClusterCommand::Dashboard => {
+                let names = show_spinner(self.spinner_msg(), async {
+                    Ok::<_, anyhow::Error>(fetch_validator_names(ctx).await)
+                })
+                .await?;
+                run_dashboard(ctx, &names).await?;
+            }
             ClusterCommand::EpochInfo => {
-                show_spinner(self.spinner_msg(), fetch_epoch_info(ctx)).await?;
+                let target: Option<EpochTarget> = prompt_optional_data(
+                    "Estimate a future `epoch <n>` or `slot <n>` (leave empty to skip):",
+                )?;
+                show_spinner(self.spinner_msg(), fetch_epoch_info(ctx, target)).await?;
             }
             ClusterCommand::CurrentSlot => {
                 show_spinner(self.spinner_msg(), fetch_current_slot(ctx)).await?;
@@ -70,8 +142,34 @@ impl ClusterCommand {
             ClusterCommand::BlockTime => {
                 show_spinner(self.spinner_msg(), fetch_block_time(ctx)).await?;
             }
+            ClusterCommand::Block => {
+                let slot: Option<u64> =
+                    prompt_optional_data("Enter slot (leave empty for the latest):")?;
+                let hide_votes = Confirm::new("Hide vote transactions?")
+                    .with_default(true)
+                    .prompt()?;
+
+                let signatures =
+                    show_spinner(self.spinner_msg(), fetch_block(ctx, slot, hide_votes)).await?;
+
+                prompt_inspect_transaction(ctx, &signatures).await?;
+            }
             ClusterCommand::Validators => {
-                show_spinner(self.spinner_msg(), fetch_validators(ctx)).await?;
+                let query = prompt_validators_query()?;
+                show_spinner(self.spinner_msg(), fetch_validators(ctx, &query)).await?;
+            }
+            ClusterCommand::ClusterNodes => {
+                let version: Option<String> = prompt_optional_data(
+                    "Filter by version prefix, e.g. 2.3 (leave empty for all):",
+                )?;
+                let staked_only = Confirm::new("Only list nodes with stake?")
+                    .with_default(true)
+                    .prompt()?;
+                show_spinner(
+                    self.spinner_msg(),
+                    fetch_cluster_nodes(ctx, version.as_deref(), staked_only),
+                )
+                .await?;
             }
             ClusterCommand::SupplyInfo => {
                 show_spinner(self.spinner_msg(), fetch_supply_info(ctx)).await?;
@@ -79,9 +177,48 @@ impl ClusterCommand {
             ClusterCommand::Inflation => {
                 show_spinner(self.spinner_msg(), fetch_inflation_info(ctx)).await?;
             }
+            ClusterCommand::InflationRewards => {
+                let addresses: PubkeyList =
+                    prompt_data("Enter stake or vote account addresses (comma separated):")?;
+                let start_epoch: Option<u64> = prompt_optional_data(&format!(
+                    "Enter start epoch (leave empty for the last {DEFAULT_REWARD_EPOCHS} epochs):"
+                ))?;
+                let end_epoch: Option<u64> =
+                    prompt_optional_data("Enter end epoch (leave empty for the last completed):")?;
+
+                let rows = show_spinner(
+                    self.spinner_msg(),
+                    fetch_inflation_rewards(ctx, addresses.pubkeys(), start_epoch, end_epoch),
+                )
+                .await?;
+
+                if !rows.is_empty()
+                    && Confirm::new("Export rewards to CSV?")
+                        .with_default(false)
+                        .prompt()?
+                {
+                    let path: PathBuf = prompt_data("Enter CSV output path:")?;
+                    export_inflation_rewards(&path, &rows)?;
+                }
+            }
+            ClusterCommand::LeaderSchedule => {
+                let identity: Option<Pubkey> = prompt_optional_data(
+                    "Enter validator identity (leave empty for the whole epoch):",
+                )?;
+                show_spinner(self.spinner_msg(), fetch_leader_schedule(ctx, identity)).await?;
+            }
+            ClusterCommand::BlockProduction => {
+                let identity: Option<Pubkey> = prompt_optional_data(
+                    "Enter validator identity (leave empty for all validators):",
+                )?;
+                show_spinner(self.spinner_msg(), fetch_block_production(ctx, identity)).await?;
+            }
             ClusterCommand::ClusterVersion => {
                 show_spinner(self.spinner_msg(), fetch_cluster_version(ctx)).await?;
             }
+            ClusterCommand::Health => {
+                show_spinner(self.spinner_msg(), process_cluster_health(ctx)).await?;
+            }
             ClusterCommand::GoBack => {
                 return Ok(CommandExec::GoBack);
             }
@@ -91,8 +228,36 @@ impl ClusterCommand {
     }
 }
 
-async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let epoch_info = ctx.rpc().get_epoch_info().await?;
+async fn fetch_epoch_info(ctx: &ScillaContext, target: Option<EpochTarget>) -> anyhow::Result<()> {
+    let (epoch_info, epoch_schedule, ms_per_slot) = tokio::try_join!(
+        async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        async {
+            ctx.rpc()
+                .get_epoch_schedule()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        fetch_average_slot_time_ms(ctx),
+    )?;
+
+    let eta = |target_slot: u64| {
+        let slots_ahead = target_slot.saturating_sub(epoch_info.absolute_slot);
+        format!(
+            "{} ({})",
+            format_duration((slots_ahead as f64 * ms_per_slot / 1000.0) as u64),
+            estimate_slot_datetime(epoch_info.absolute_slot, target_slot, ms_per_slot)
+                .format("%Y-%m-%d %H:%M UTC")
+        )
+    };
+
+    let completion = epoch_info.slot_index as f64 / epoch_info.slots_in_epoch as f64 * 100.0;
+    let next_epoch_slot =
+        epoch_info.absolute_slot - epoch_info.slot_index + epoch_info.slots_in_epoch;
 
     let mut table = Table::new();
     table
@@ -113,6 +278,13 @@ async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new("Slots in Epoch"),
             Cell::new(format!("{}", epoch_info.slots_in_epoch)),
         ])
+        .add_row(vec![
+            Cell::new("Completion"),
+            Cell::new(format!(
+                "{} {completion:.2}%",
+                progress_bar(completion / 100.0, EPOCH_PROGRESS_BAR_WIDTH)
+            )),
+        ])
         .add_row(vec![
             Cell::new("Absolute Slot"),
             Cell::new(format!("{}", epoch_info.absolute_slot)),
@@ -124,11 +296,110 @@ async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
         .add_row(vec![
             Cell::new("Transaction Count"),
             Cell::new(format!("{}", epoch_info.transaction_count.unwrap_or(0))),
+        ])
+        .add_row(vec![
+            Cell::new("Average Slot Time"),
+            Cell::new(format!("{ms_per_slot:.0} ms")),
+        ])
+        .add_row(vec![
+            Cell::new(format!("Epoch {} Starts", epoch_info.epoch + 1)),
+            Cell::new(format!(
+                "slot {next_epoch_slot}, in {}",
+                eta(next_epoch_slot)
+            )),
         ]);
 
     println!("\n{}", style("EPOCH INFORMATION").green().bold());
     println!("{table}");
 
+    let mut schedule_table = Table::new();
+    schedule_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Slots per Epoch"),
+            Cell::new(epoch_schedule.slots_per_epoch),
+        ])
+        .add_row(vec![
+            Cell::new("Leader Schedule Slot Offset"),
+            Cell::new(epoch_schedule.leader_schedule_slot_offset),
+        ])
+        .add_row(vec![
+            Cell::new("Warmup"),
+            Cell::new(if epoch_schedule.warmup {
+                "Enabled"
+            } else {
+                "Disabled"
+            }),
+        ])
+        .add_row(vec![
+            Cell::new("First Normal Epoch"),
+            Cell::new(epoch_schedule.first_normal_epoch),
+        ])
+        .add_row(vec![
+            Cell::new("First Normal Slot"),
+            Cell::new(epoch_schedule.first_normal_slot),
+        ]);
+
+    println!("\n{}", style("EPOCH SCHEDULE").green().bold());
+    println!("{schedule_table}");
+
+    let Some(target) = target else {
+        return Ok(());
+    };
+    let (label, target_slot) = match target {
+        EpochTarget::Epoch(epoch) => {
+            if epoch <= epoch_info.epoch {
+                bail!(
+                    "Epoch {epoch} has already started, the current epoch is {}",
+                    epoch_info.epoch
+                );
+            }
+            (
+                format!("Epoch {epoch} Starts"),
+                epoch_schedule.get_first_slot_in_epoch(epoch),
+            )
+        }
+        EpochTarget::Slot(slot) => {
+            if slot <= epoch_info.absolute_slot {
+                bail!(
+                    "Slot {slot} has already passed, the current slot is {}",
+                    epoch_info.absolute_slot
+                );
+            }
+            (
+                format!("Slot {slot} (epoch {})", epoch_schedule.get_epoch(slot)),
+                slot,
+            )
+        }
+    };
+
+    let mut estimate_table = Table::new();
+    estimate_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Target").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Slot").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Slots Away").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Estimated In").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new(label),
+            Cell::new(target_slot),
+            Cell::new(target_slot - epoch_info.absolute_slot),
+            Cell::new(eta(target_slot)),
+        ]);
+
+    println!("\n{}", style("ESTIMATE").green().bold());
+    println!("{estimate_table}");
+    println!(
+        "{}",
+        style("Estimates assume the recent slot time holds; long horizons drift").dim()
+    );
+
     Ok(())
 }
 
@@ -202,8 +473,330 @@ async fn fetch_block_time(ctx: &ScillaContext) -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn fetch_validators(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let validators = ctx.rpc().get_vote_accounts().await?;
+/// Column to order the validators table by
+#[derive(Debug, Clone, Copy)]
+pub enum ValidatorSort {
+    Stake,
+    Commission,
+    LastVote,
+    Credits,
+}
+
+impl fmt::Display for ValidatorSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValidatorSort::Stake => "Activated stake",
+            ValidatorSort::Commission => "Commission",
+            ValidatorSort::LastVote => "Last vote",
+            ValidatorSort::Credits => "Epoch credits",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ValidatorStatusFilter {
+    All,
+    Current,
+    Delinquent,
+}
+
+impl fmt::Display for ValidatorStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValidatorStatusFilter::All => "All",
+            ValidatorStatusFilter::Current => "Current only",
+            ValidatorStatusFilter::Delinquent => "Delinquent only",
+        })
+    }
+}
+
+/// How the validators table should be sorted and filtered
+#[derive(Debug, Clone)]
+pub struct ValidatorsQuery {
+    pub sort: ValidatorSort,
+    pub status: ValidatorStatusFilter,
+    pub min_commission: Option<u8>,
+    pub max_commission: Option<u8>,
+    pub search: Option<String>,
+}
+
+fn prompt_validators_query() -> anyhow::Result<ValidatorsQuery> {
+    let sort = Select::new(
+        "Sort validators by:",
+        vec![
+            ValidatorSort::Stake,
+            ValidatorSort::Commission,
+            ValidatorSort::LastVote,
+            ValidatorSort::Credits,
+        ],
+    )
+    .prompt()?;
+
+    let status = Select::new(
+        "Show validators:",
+        vec![
+            ValidatorStatusFilter::All,
+            ValidatorStatusFilter::Current,
+            ValidatorStatusFilter::Delinquent,
+        ],
+    )
+    .prompt()?;
+
+    let mut query = ValidatorsQuery {
+        sort,
+        status,
+        min_commission: None,
+        max_commission: None,
+        search: None,
+    };
+
+    if Confirm::new("Filter by commission or search by pubkey?")
+        .with_default(false)
+        .prompt()?
+    {
+        query.min_commission =
+            prompt_optional_data::<Commission>("Enter minimum commission (leave empty for none):")?
+                .map(|c| c.value());
+        query.max_commission =
+            prompt_optional_data::<Commission>("Enter maximum commission (leave empty for none):")?
+                .map(|c| c.value());
+        query.search =
+            prompt_optional_data("Search identity or vote pubkey (leave empty for none):")?;
+    }
+
+    Ok(query)
+}
+
+/// Summary of one transaction within a block
+struct BlockTransactionRow {
+    signature: Signature,
+    fee_payer: Pubkey,
+    fee: u64,
+    compute_units: Option<u64>,
+    success: bool,
+    programs: Vec<Pubkey>,
+}
+
+async fn fetch_block(
+    ctx: &ScillaContext,
+    slot: Option<u64>,
+    hide_votes: bool,
+) -> anyhow::Result<Vec<Signature>> {
+    let slot = match slot {
+        Some(slot) => slot,
+        None => ctx.rpc().get_slot().await?,
+    };
+
+    let block = ctx
+        .rpc()
+        .get_block_with_config(
+            slot,
+            RpcBlockConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                transaction_details: Some(TransactionDetails::Full),
+                rewards: Some(true),
+                commitment: Some(ctx.rpc().commitment()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .await?;
+
+    let transactions = block.transactions.unwrap_or_default();
+    let total_transactions = transactions.len();
+
+    let mut rows = Vec::with_capacity(total_transactions);
+    let mut vote_transactions = 0;
+    for tx in &transactions {
+        let Some(decoded) = tx.transaction.decode() else {
+            continue;
+        };
+        let keys = decoded.message.static_account_keys();
+        let mut programs: Vec<Pubkey> = Vec::new();
+        for ix in decoded.message.instructions() {
+            if let Some(program) = keys.get(ix.program_id_index as usize)
+                && !programs.contains(program)
+            {
+                programs.push(*program);
+            }
+        }
+
+        // `all` holds for an empty list, so a transaction without instructions
+        // must not count as a vote
+        if !programs.is_empty() && programs.iter().all(|program| *program == vote::id()) {
+            vote_transactions += 1;
+            if hide_votes {
+                continue;
+            }
+        }
+
+        let meta = tx.meta.as_ref();
+        rows.push(BlockTransactionRow {
+            signature: decoded.signatures.first().copied().unwrap_or_default(),
+            fee_payer: keys.first().copied().unwrap_or_default(),
+            fee: meta.map(|m| m.fee).unwrap_or_default(),
+            compute_units: meta.and_then(|m| m.compute_units_consumed.clone().into()),
+            success: meta.is_none_or(|m| m.err.is_none()),
+            programs,
+        });
+    }
+
+    let block_time = block
+        .block_time
+        .and_then(chrono::DateTime::<chrono::Utc>::from_timestamp_secs)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "~".to_string());
+    let total_fees: u64 = transactions
+        .iter()
+        .filter_map(|tx| tx.meta.as_ref().map(|m| m.fee))
+        .sum();
+
+    let mut summary_table = Table::new();
+    summary_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Slot"), Cell::new(slot)])
+        .add_row(vec![Cell::new("Parent Slot"), Cell::new(block.parent_slot)])
+        .add_row(vec![
+            Cell::new("Block Height"),
+            Cell::new(
+                block
+                    .block_height
+                    .map(|height| height.to_string())
+                    .unwrap_or_else(|| "~".to_string()),
+            ),
+        ])
+        .add_row(vec![Cell::new("Block Time"), Cell::new(block_time)])
+        .add_row(vec![Cell::new("Blockhash"), Cell::new(&block.blockhash)])
+        .add_row(vec![
+            Cell::new("Previous Blockhash"),
+            Cell::new(&block.previous_blockhash),
+        ])
+        .add_row(vec![
+            Cell::new("Transactions"),
+            Cell::new(format!(
+                "{total_transactions} ({vote_transactions} vote, {} non-vote)",
+                total_transactions - vote_transactions
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Total Fees (SOL)"),
+            Cell::new(format!("{:.9}", lamports_to_sol(total_fees))),
+        ]);
+
+    println!("\n{}", style("BLOCK").green().bold());
+    println!("{summary_table}");
+
+    if !rows.is_empty() {
+        let mut tx_table = Table::new();
+        tx_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Signature").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Fee Payer").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Fee (lamports)").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Compute Units").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Programs").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (idx, row) in rows.iter().enumerate() {
+            tx_table.add_row(vec![
+                Cell::new(idx + 1),
+                Cell::new(row.signature),
+                Cell::new(row.fee_payer),
+                Cell::new(row.fee),
+                Cell::new(
+                    row.compute_units
+                        .map(|units| units.to_string())
+                        .unwrap_or_else(|| "~".to_string()),
+                ),
+                Cell::new(if row.success {
+                    style("Success").green()
+                } else {
+                    style("Failed").red()
+                }),
+                Cell::new(
+                    row.programs
+                        .iter()
+                        .map(program_label)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+            ]);
+        }
+
+        println!("\n{}", style("BLOCK TRANSACTIONS").green().bold());
+        println!("{tx_table}");
+    }
+
+    if let Some(rewards) = block.rewards.filter(|rewards| !rewards.is_empty()) {
+        let mut rewards_table = Table::new();
+        rewards_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Type").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Amount (SOL)").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Post Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for reward in &rewards {
+            let amount = lamports_to_sol(reward.lamports.unsigned_abs());
+            rewards_table.add_row(vec![
+                Cell::new(&reward.pubkey),
+                Cell::new(
+                    reward
+                        .reward_type
+                        .map(|reward_type| reward_type.to_string())
+                        .unwrap_or_else(|| "~".to_string()),
+                ),
+                Cell::new(if reward.lamports < 0 {
+                    format!("-{amount:.9}")
+                } else {
+                    format!("{amount:.9}")
+                }),
+                Cell::new(format!("{:.9}", lamports_to_sol(reward.post_balance))),
+            ]);
+        }
+
+        println!("\n{}", style("BLOCK REWARDS").green().bold());
+        println!("{rewards_table}");
+    }
+
+    Ok(rows.into_iter().map(|row| row.signature).collect())
+}
+
+async fn fetch_validators(ctx: &ScillaContext, query: &ValidatorsQuery) -> anyhow::Result<()> {
+    let (validators, names) =
+        tokio::join!(ctx.rpc().get_vote_accounts(), fetch_validator_names(ctx));
+    let validators = validators?;
+
+    let current_stake: u64 = validators.current.iter().map(|v| v.activated_stake).sum();
+    let delinquent_stake: u64 = validators
+        .delinquent
+        .iter()
+        .map(|v| v.activated_stake)
+        .sum();
+    let total_stake = current_stake + delinquent_stake;
+    let pct_of_total = |stake: u64| {
+        if total_stake == 0 {
+            0.0
+        } else {
+            stake as f64 / total_stake as f64 * 100.0
+        }
+    };
+
+    let mut stakes: Vec<u64> = validators
+        .current
+        .iter()
+        .chain(&validators.delinquent)
+        .map(|v| v.activated_stake)
+        .collect();
+    stakes.sort_unstable_by(|a, b| b.cmp(a));
+
+    let nakamoto = validators_to_exceed(&stakes, 1.0 / 3.0);
+    let superminority_stake: u64 = stakes.iter().take(nakamoto).sum();
+    let supermajority = validators_to_exceed(&stakes, 2.0 / 3.0);
 
     // Summary table
     let mut summary_table = Table::new();
@@ -220,35 +813,330 @@ async fn fetch_validators(ctx: &ScillaContext) -> anyhow::Result<()> {
         .add_row(vec![
             Cell::new("Delinquent Validators"),
             Cell::new(format!("{}", validators.delinquent.len())),
+        ])
+        .add_row(vec![
+            Cell::new("Total Active Stake (SOL)"),
+            Cell::new(format!("{:.2}", lamports_to_sol(total_stake))),
+        ])
+        .add_row(vec![
+            Cell::new("Delinquent Stake"),
+            Cell::new(format!(
+                "{:.2} SOL ({:.2}%)",
+                lamports_to_sol(delinquent_stake),
+                pct_of_total(delinquent_stake)
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Nakamoto Coefficient (>1/3 stake)"),
+            Cell::new(nakamoto),
+        ])
+        .add_row(vec![
+            Cell::new("Superminority Stake"),
+            Cell::new(format!(
+                "{:.2} SOL ({:.2}%)",
+                lamports_to_sol(superminority_stake),
+                pct_of_total(superminority_stake)
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Supermajority Size (>2/3 stake)"),
+            Cell::new(supermajority),
         ]);
 
     println!("\n{}", style("VALIDATORS SUMMARY").green().bold());
     println!("{summary_table}");
 
+    let include_current = !matches!(query.status, ValidatorStatusFilter::Delinquent);
+    let include_delinquent = !matches!(query.status, ValidatorStatusFilter::Current);
+    let search = query.search.as_deref().map(str::to_lowercase);
+
+    let mut rows: Vec<(&RpcVoteAccountInfo, bool)> = validators
+        .current
+        .iter()
+        .filter(|_| include_current)
+        .map(|v| (v, false))
+        .chain(
+            validators
+                .delinquent
+                .iter()
+                .filter(|_| include_delinquent)
+                .map(|v| (v, true)),
+        )
+        .filter(|(v, _)| query.min_commission.is_none_or(|min| v.commission >= min))
+        .filter(|(v, _)| query.max_commission.is_none_or(|max| v.commission <= max))
+        .filter(|(v, _)| {
+            search.as_deref().is_none_or(|search| {
+                v.node_pubkey.to_lowercase().contains(search)
+                    || v.vote_pubkey.to_lowercase().contains(search)
+            })
+        })
+        .collect();
+
+    let epoch_credits = |v: &RpcVoteAccountInfo| {
+        v.epoch_credits
+            .last()
+            .map(|(_, credits, prev_credits)| credits.saturating_sub(*prev_credits))
+            .unwrap_or(0)
+    };
+
+    match query.sort {
+        ValidatorSort::Stake => rows.sort_by_key(|(v, _)| std::cmp::Reverse(v.activated_stake)),
+        ValidatorSort::Commission => rows.sort_by(|a, b| {
+            a.0.commission
+                .cmp(&b.0.commission)
+                .then(b.0.activated_stake.cmp(&a.0.activated_stake))
+        }),
+        ValidatorSort::LastVote => rows.sort_by_key(|(v, _)| std::cmp::Reverse(v.last_vote)),
+        ValidatorSort::Credits => rows.sort_by_key(|(v, _)| std::cmp::Reverse(epoch_credits(v))),
+    }
+
+    if rows.is_empty() {
+        println!(
+            "\n{}",
+            style("No validators match the selected filters").yellow()
+        );
+        return Ok(());
+    }
+
     // Validators detail table
-    if !validators.current.is_empty() {
-        let mut validators_table = Table::new();
-        validators_table.load_preset(UTF8_FULL).set_header(vec![
-            Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
-            Cell::new("Node Pubkey").add_attribute(comfy_table::Attribute::Bold),
-            Cell::new("Vote Account").add_attribute(comfy_table::Attribute::Bold),
-            Cell::new("Activated Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    let mut validators_table = Table::new();
+    validators_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Node Pubkey").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Vote Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Activated Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Stake %").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Commission").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Last Vote").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Epoch Credits").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, (validator, delinquent)) in rows.iter().enumerate() {
+        let stake_sol = (validator.activated_stake as f64).div(LAMPORTS_PER_SOL as f64);
+        let name = validator_name(&names, &validator.node_pubkey);
+        validators_table.add_row(vec![
+            Cell::new(format!("{}", idx + 1)),
+            Cell::new(name),
+            Cell::new(&validator.node_pubkey),
+            Cell::new(&validator.vote_pubkey),
+            Cell::new(format!("{stake_sol:.2}")),
+            Cell::new(format!("{:.3}%", pct_of_total(validator.activated_stake))),
+            Cell::new(format!("{}%", validator.commission)),
+            Cell::new(validator.last_vote),
+            Cell::new(epoch_credits(validator)),
+            Cell::new(if *delinquent {
+                style("Delinquent").red()
+            } else {
+                style("Current").green()
+            }),
         ]);
+    }
 
-        for (idx, validator) in validators.current.iter().enumerate() {
-            let stake_sol = (validator.activated_stake as f64).div(LAMPORTS_PER_SOL as f64);
-            validators_table.add_row(vec![
-                Cell::new(format!("{}", idx + 1)),
-                Cell::new(&validator.node_pubkey),
-                Cell::new(&validator.vote_pubkey),
-                Cell::new(format!("{stake_sol:.2}")),
-            ]);
+    println!(
+        "\n{} {}",
+        style("VALIDATORS").green().bold(),
+        style(format!("(sorted by {}, {} shown)", query.sort, rows.len())).dim()
+    );
+    println!("{validators_table}");
+
+    Ok(())
+}
+
+fn node_version(node: &RpcContactInfo) -> &str {
+    node.version.as_deref().unwrap_or("unknown")
+}
+
+/// Vote account and stake backing a gossip node
+struct NodeStake<'a> {
+    vote_pubkey: &'a str,
+    stake: u64,
+    delinquent: bool,
+}
+
+/// Lists gossip nodes joined with their vote accounts, after a breakdown of
+/// which versions the stake runs
+async fn fetch_cluster_nodes(
+    ctx: &ScillaContext,
+    version: Option<&str>,
+    staked_only: bool,
+) -> anyhow::Result<()> {
+    let (nodes, vote_accounts, names) = tokio::join!(
+        ctx.rpc().get_cluster_nodes(),
+        ctx.rpc().get_vote_accounts(),
+        fetch_validator_names(ctx)
+    );
+    let (nodes, vote_accounts) = (nodes?, vote_accounts?);
+
+    // A node may run several vote accounts; its stake is their sum
+    let mut stakes: HashMap<&str, NodeStake> = HashMap::new();
+    for (info, delinquent) in vote_accounts
+        .current
+        .iter()
+        .map(|info| (info, false))
+        .chain(vote_accounts.delinquent.iter().map(|info| (info, true)))
+    {
+        stakes
+            .entry(info.node_pubkey.as_str())
+            .and_modify(|node| node.stake += info.activated_stake)
+            .or_insert(NodeStake {
+                vote_pubkey: &info.vote_pubkey,
+                stake: info.activated_stake,
+                delinquent,
+            });
+    }
+    let total_stake: u64 = stakes.values().map(|node| node.stake).sum();
+    let pct_of_total = |stake: u64| {
+        if total_stake == 0 {
+            0.0
+        } else {
+            stake as f64 / total_stake as f64 * 100.0
         }
+    };
+    let node_stake =
+        |node: &RpcContactInfo| stakes.get(node.pubkey.as_str()).map_or(0, |s| s.stake);
+
+    let gossip_stake: u64 = nodes.iter().map(node_stake).sum();
+    let mut shred_versions: Vec<u16> = nodes.iter().filter_map(|n| n.shred_version).collect();
+    shred_versions.sort_unstable();
+    shred_versions.dedup();
+
+    let mut summary_table = Table::new();
+    summary_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Gossip Nodes"), Cell::new(nodes.len())])
+        .add_row(vec![
+            Cell::new("Staked Nodes"),
+            Cell::new(nodes.iter().filter(|n| node_stake(n) > 0).count()),
+        ])
+        .add_row(vec![
+            Cell::new("RPC Nodes"),
+            Cell::new(nodes.iter().filter(|n| n.rpc.is_some()).count()),
+        ])
+        .add_row(vec![
+            Cell::new("Shred Versions"),
+            Cell::new(
+                shred_versions
+                    .iter()
+                    .map(u16::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Stake Not in Gossip"),
+            Cell::new(format!(
+                "{:.2} SOL ({:.2}%)",
+                lamports_to_sol(total_stake - gossip_stake),
+                pct_of_total(total_stake - gossip_stake)
+            )),
+        ]);
+
+    println!("\n{}", style("CLUSTER NODES").green().bold());
+    println!("{summary_table}");
 
-        println!("\n{}", style("TOP VALIDATORS").green().bold());
-        println!("{validators_table}");
+    let mut versions_table = Table::new();
+    versions_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Version").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Nodes").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Stake %").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for share in stake_by_version(
+        nodes
+            .iter()
+            .map(|node| (node_version(node), node_stake(node))),
+    ) {
+        let pct = pct_of_total(share.stake);
+        versions_table.add_row(vec![
+            Cell::new(&share.version),
+            Cell::new(share.nodes),
+            Cell::new(format!("{:.2}", lamports_to_sol(share.stake))),
+            Cell::new(format!(
+                "{} {pct:.2}%",
+                progress_bar(pct / 100.0, NODE_VERSION_BAR_WIDTH)
+            )),
+        ]);
     }
 
+    println!("\n{}", style("VERSION DISTRIBUTION").green().bold());
+    println!("{versions_table}");
+
+    let mut rows: Vec<&RpcContactInfo> = nodes
+        .iter()
+        .filter(|node| version.is_none_or(|prefix| node_version(node).starts_with(prefix)))
+        .filter(|node| !staked_only || node_stake(node) > 0)
+        .collect();
+    rows.sort_by_key(|node| std::cmp::Reverse(node_stake(node)));
+
+    if rows.is_empty() {
+        println!(
+            "\n{}",
+            style("No nodes match the selected filters").yellow()
+        );
+        return Ok(());
+    }
+
+    let address = |addr: Option<std::net::SocketAddr>| {
+        addr.map_or_else(|| "-".to_string(), |a| a.to_string())
+    };
+    let mut nodes_table = Table::new();
+    nodes_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Vote Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Version").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Feature Set").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Shred Version").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Gossip").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("TPU (QUIC)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("RPC").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, node) in rows.iter().enumerate() {
+        let stake = stakes.get(node.pubkey.as_str());
+        let vote_account = match stake {
+            Some(stake) if stake.delinquent => {
+                Cell::new(style(format!("{} (delinquent)", stake.vote_pubkey)).red())
+            }
+            Some(stake) => Cell::new(stake.vote_pubkey),
+            None => Cell::new("-"),
+        };
+        nodes_table.add_row(vec![
+            Cell::new(idx + 1),
+            Cell::new(validator_name(&names, &node.pubkey)),
+            Cell::new(&node.pubkey),
+            vote_account,
+            Cell::new(format!("{:.2}", lamports_to_sol(node_stake(node)))),
+            Cell::new(node_version(node)),
+            Cell::new(
+                node.feature_set
+                    .map_or_else(|| "-".to_string(), |set| set.to_string()),
+            ),
+            Cell::new(
+                node.shred_version
+                    .map_or_else(|| "-".to_string(), |shred| shred.to_string()),
+            ),
+            Cell::new(address(node.gossip)),
+            Cell::new(address(node.tpu_quic)),
+            Cell::new(address(node.rpc)),
+        ]);
+    }
+
+    println!(
+        "\n{} {}",
+        style("NODES").green().bold(),
+        style(format!("(sorted by stake, {} shown)", rows.len())).dim()
+    );
+    println!("{nodes_table}");
+
     Ok(())
 }
 
@@ -322,6 +1210,482 @@ async fn fetch_inflation_info(ctx: &ScillaContext) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// A single address' inflation reward for one epoch
+struct RewardRow {
+    address: Pubkey,
+    epoch: u64,
+    effective_slot: u64,
+    amount: u64,
+    post_balance: u64,
+    commission: Option<u8>,
+    apy: f64,
+}
+
+async fn fetch_inflation_rewards(
+    ctx: &ScillaContext,
+    addresses: &[Pubkey],
+    start_epoch: Option<u64>,
+    end_epoch: Option<u64>,
+) -> anyhow::Result<Vec<RewardRow>> {
+    let epoch_info = ctx.rpc().get_epoch_info().await?;
+
+    // Rewards for an epoch are only paid out once the next one begins
+    let Some(last_completed) = epoch_info.epoch.checked_sub(1) else {
+        bail!("No completed epochs yet");
+    };
+
+    let end_epoch = end_epoch.unwrap_or(last_completed);
+    let start_epoch =
+        start_epoch.unwrap_or_else(|| end_epoch.saturating_sub(DEFAULT_REWARD_EPOCHS - 1));
+
+    if end_epoch > last_completed {
+        bail!("End epoch {end_epoch} has not completed yet (last completed: {last_completed})");
+    }
+    if start_epoch > end_epoch {
+        bail!("Start epoch {start_epoch} is after end epoch {end_epoch}");
+    }
+
+    let epochs_per_year = epochs_per_year(epoch_info.slots_in_epoch);
+    let mut rows = Vec::new();
+
+    for epoch in start_epoch..=end_epoch {
+        let rewards = ctx
+            .rpc()
+            .get_inflation_reward(addresses, Some(epoch))
+            .await?;
+
+        for (address, reward) in addresses.iter().zip(rewards) {
+            let Some(reward) = reward else {
+                continue;
+            };
+
+            let pre_balance = reward.post_balance.saturating_sub(reward.amount);
+            let apy = if pre_balance == 0 {
+                0.0
+            } else {
+                annualized_apy(reward.amount as f64 / pre_balance as f64, epochs_per_year)
+            };
+
+            rows.push(RewardRow {
+                address: *address,
+                epoch: reward.epoch,
+                effective_slot: reward.effective_slot,
+                amount: reward.amount,
+                post_balance: reward.post_balance,
+                commission: reward.commission,
+                apy,
+            });
+        }
+    }
+
+    if rows.is_empty() {
+        println!(
+            "\n{}",
+            style(format!(
+                "No inflation rewards found between epochs {start_epoch} and {end_epoch}"
+            ))
+            .yellow()
+        );
+        return Ok(rows);
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Reward (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Post Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Commission").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("APY").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for row in &rows {
+        table.add_row(vec![
+            Cell::new(row.epoch),
+            Cell::new(row.address),
+            Cell::new(format!("{:.9}", lamports_to_sol(row.amount))),
+            Cell::new(format!("{:.9}", lamports_to_sol(row.post_balance))),
+            Cell::new(
+                row.commission
+                    .map(|c| format!("{c}%"))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(format!("{:.2}%", row.apy)),
+        ]);
+    }
+
+    println!("\n{}", style("INFLATION REWARDS").green().bold());
+    println!("{table}");
+
+    // (epochs rewarded, total lamports, summed APY) per address
+    let mut totals: BTreeMap<Pubkey, (u64, u64, f64)> = BTreeMap::new();
+    for row in &rows {
+        let entry = totals.entry(row.address).or_default();
+        entry.0 += 1;
+        entry.1 += row.amount;
+        entry.2 += row.apy;
+    }
+
+    let mut totals_table = Table::new();
+    totals_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Epochs").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Total Reward (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Average APY").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (address, (epochs, total, apy_sum)) in &totals {
+        totals_table.add_row(vec![
+            Cell::new(address),
+            Cell::new(epochs),
+            Cell::new(format!("{:.9}", lamports_to_sol(*total))),
+            Cell::new(format!("{:.2}%", apy_sum / *epochs as f64)),
+        ]);
+    }
+
+    println!("\n{}", style("REWARD TOTALS").green().bold());
+    println!("{totals_table}");
+
+    Ok(rows)
+}
+
+fn export_inflation_rewards(path: &Path, rows: &[RewardRow]) -> anyhow::Result<()> {
+    let records = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.epoch.to_string(),
+                row.address.to_string(),
+                row.effective_slot.to_string(),
+                row.amount.to_string(),
+                format!("{:.9}", lamports_to_sol(row.amount)),
+                row.post_balance.to_string(),
+                row.commission.map(|c| c.to_string()).unwrap_or_default(),
+                format!("{:.4}", row.apy),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    write_csv(
+        path,
+        &[
+            "epoch",
+            "address",
+            "effective_slot",
+            "reward_lamports",
+            "reward_sol",
+            "post_balance_lamports",
+            "commission",
+            "apy_percent",
+        ],
+        &records,
+    )?;
+
+    println!(
+        "{}",
+        style(format!("Rewards exported to {}", path.display())).green()
+    );
+
+    Ok(())
+}
+
+/// Splits sorted slots into runs of consecutive slots, i.e. leader windows
+fn leader_windows(slots: &[u64]) -> Vec<(u64, u64)> {
+    let mut windows: Vec<(u64, u64)> = Vec::new();
+    for &slot in slots {
+        match windows.last_mut() {
+            Some((_, end)) if *end + 1 == slot => *end = slot,
+            _ => windows.push((slot, slot)),
+        }
+    }
+    windows
+}
+
+async fn fetch_leader_schedule(
+    ctx: &ScillaContext,
+    identity: Option<Pubkey>,
+) -> anyhow::Result<()> {
+    let (epoch_info, ms_per_slot) = tokio::try_join!(
+        async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        fetch_average_slot_time_ms(ctx)
+    )?;
+
+    let schedule = ctx
+        .rpc()
+        .get_leader_schedule_with_config(
+            None,
+            RpcLeaderScheduleConfig {
+                identity: identity.map(|identity| identity.to_string()),
+                commitment: Some(ctx.rpc().commitment()),
+            },
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Leader schedule is not available for this epoch"))?;
+
+    let current_slot = epoch_info.absolute_slot;
+    let epoch_start = current_slot - epoch_info.slot_index;
+
+    // Leader schedule slots are relative to the first slot of the epoch
+    let mut by_leader: Vec<(String, Vec<u64>)> = schedule
+        .into_iter()
+        .map(|(leader, slots)| {
+            let slots = slots
+                .into_iter()
+                .map(|slot| epoch_start + slot as u64)
+                .collect();
+            (leader, slots)
+        })
+        .collect();
+
+    if by_leader.is_empty() {
+        println!(
+            "\n{}",
+            style("No leader slots scheduled in the current epoch").yellow()
+        );
+        return Ok(());
+    }
+
+    let format_eta = |slot: u64| {
+        let eta = estimate_slot_datetime(current_slot, slot, ms_per_slot);
+        let secs = ((slot - current_slot) as f64 * ms_per_slot / 1000.0) as u64;
+        format!(
+            "{} (in {})",
+            eta.format("%Y-%m-%d %H:%M:%S UTC"),
+            format_duration(secs)
+        )
+    };
+
+    if let Some(identity) = identity {
+        let (_, mut slots) = by_leader.remove(0);
+        slots.sort_unstable();
+        let upcoming: Vec<u64> = slots
+            .iter()
+            .copied()
+            .filter(|s| *s >= current_slot)
+            .collect();
+
+        let mut summary_table = Table::new();
+        summary_table
+            .load_preset(UTF8_FULL)
+            .set_header(vec![
+                Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+            ])
+            .add_row(vec![Cell::new("Identity"), Cell::new(identity)])
+            .add_row(vec![Cell::new("Epoch"), Cell::new(epoch_info.epoch)])
+            .add_row(vec![Cell::new("Leader Slots"), Cell::new(slots.len())])
+            .add_row(vec![
+                Cell::new("Completed"),
+                Cell::new(slots.len() - upcoming.len()),
+            ])
+            .add_row(vec![Cell::new("Upcoming"), Cell::new(upcoming.len())])
+            .add_row(vec![
+                Cell::new("Avg Slot Time"),
+                Cell::new(format!("{ms_per_slot:.0} ms")),
+            ]);
+
+        println!("\n{}", style("LEADER SCHEDULE").green().bold());
+        println!("{summary_table}");
+
+        if upcoming.is_empty() {
+            println!(
+                "\n{}",
+                style("No remaining leader slots this epoch").yellow()
+            );
+            return Ok(());
+        }
+
+        let windows = leader_windows(&upcoming);
+        let mut windows_table = Table::new();
+        windows_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("First Slot").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Last Slot").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Estimated Time").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for (first, last) in windows.iter().take(LEADER_WINDOW_LIMIT) {
+            windows_table.add_row(vec![
+                Cell::new(first),
+                Cell::new(last),
+                Cell::new(format_eta(*first)),
+            ]);
+        }
+
+        println!(
+            "\n{} {}",
+            style("UPCOMING LEADER WINDOWS").green().bold(),
+            style(format!(
+                "(showing {} of {})",
+                windows.len().min(LEADER_WINDOW_LIMIT),
+                windows.len()
+            ))
+            .dim()
+        );
+        println!("{windows_table}");
+
+        return Ok(());
+    }
+
+    let names = fetch_validator_names(ctx).await;
+    by_leader.sort_by_key(|(_, slots)| std::cmp::Reverse(slots.len()));
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Leader Slots").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Share").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Next Leader Slot").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, (leader, slots)) in by_leader.iter().enumerate() {
+        let name = validator_name(&names, leader);
+        let next = slots
+            .iter()
+            .copied()
+            .filter(|slot| *slot >= current_slot)
+            .min()
+            .map(|slot| format!("{slot} — {}", format_eta(slot)))
+            .unwrap_or_else(|| "-".to_string());
+
+        table.add_row(vec![
+            Cell::new(idx + 1),
+            Cell::new(name),
+            Cell::new(leader),
+            Cell::new(slots.len()),
+            Cell::new(format!(
+                "{:.3}%",
+                slots.len() as f64 / epoch_info.slots_in_epoch as f64 * 100.0
+            )),
+            Cell::new(next),
+        ]);
+    }
+
+    println!(
+        "\n{} {}",
+        style(format!("LEADER SCHEDULE — EPOCH {}", epoch_info.epoch))
+            .green()
+            .bold(),
+        style(format!("(avg slot time {ms_per_slot:.0} ms)")).dim()
+    );
+    println!("{table}");
+
+    Ok(())
+}
+
+async fn fetch_block_production(
+    ctx: &ScillaContext,
+    identity: Option<Pubkey>,
+) -> anyhow::Result<()> {
+    let (production, names) = tokio::join!(
+        ctx.rpc()
+            .get_block_production_with_config(RpcBlockProductionConfig {
+                identity: identity.map(|identity| identity.to_string()),
+                range: None,
+                commitment: Some(ctx.rpc().commitment()),
+            }),
+        fetch_validator_names(ctx)
+    );
+    let production = production?.value;
+
+    if production.by_identity.is_empty() {
+        println!(
+            "\n{}",
+            style("No block production data for this range").yellow()
+        );
+        return Ok(());
+    }
+
+    let skip_rate = |leader_slots: usize, produced: usize| {
+        if leader_slots == 0 {
+            0.0
+        } else {
+            (leader_slots - produced) as f64 / leader_slots as f64 * 100.0
+        }
+    };
+
+    let (total_leader_slots, total_produced) = production
+        .by_identity
+        .values()
+        .fold((0, 0), |(slots, produced), (s, p)| {
+            (slots + s, produced + p)
+        });
+
+    let mut summary_table = Table::new();
+    summary_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Slot Range"),
+            Cell::new(format!(
+                "{} – {}",
+                production.range.first_slot, production.range.last_slot
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Leader Slots"),
+            Cell::new(total_leader_slots),
+        ])
+        .add_row(vec![
+            Cell::new("Blocks Produced"),
+            Cell::new(total_produced),
+        ])
+        .add_row(vec![
+            Cell::new("Skipped Slots"),
+            Cell::new(total_leader_slots - total_produced),
+        ])
+        .add_row(vec![
+            Cell::new("Skip Rate"),
+            Cell::new(format!(
+                "{:.2}%",
+                skip_rate(total_leader_slots, total_produced)
+            )),
+        ]);
+
+    println!("\n{}", style("BLOCK PRODUCTION").green().bold());
+    println!("{summary_table}");
+
+    let mut rows: Vec<_> = production.by_identity.iter().collect();
+    rows.sort_by_key(|(_, (leader_slots, _))| std::cmp::Reverse(*leader_slots));
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Leader Slots").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Blocks Produced").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Skipped").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Skip Rate").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, (leader, (leader_slots, produced))) in rows.into_iter().enumerate() {
+        let name = validator_name(&names, leader);
+        table.add_row(vec![
+            Cell::new(idx + 1),
+            Cell::new(name),
+            Cell::new(leader),
+            Cell::new(leader_slots),
+            Cell::new(produced),
+            Cell::new(leader_slots - produced),
+            Cell::new(format!("{:.2}%", skip_rate(*leader_slots, *produced))),
+        ]);
+    }
+
+    println!("\n{}", style("SKIP RATE BY VALIDATOR").green().bold());
+    println!("{table}");
+
+    Ok(())
+}
+
 async fn fetch_cluster_version(ctx: &ScillaContext) -> anyhow::Result<()> {
     let version = ctx.rpc().get_version().await?;
 
@@ -349,3 +1713,461 @@ async fn fetch_cluster_version(ctx: &ScillaContext) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn slot_cell(slot: &Result<u64, String>) -> Cell {
+    match slot {
+        Ok(slot) => Cell::new(slot),
+        Err(err) => Cell::new(style(err).red()),
+    }
+}
+
+fn health_cell(report: &EndpointReport) -> Cell {
+    match &report.health {
+        Ok(()) => Cell::new(style("Healthy").green()),
+        Err(err) => Cell::new(style(err).red()),
+    }
+}
+
+fn version_text(report: &EndpointReport) -> (String, String) {
+    match &report.version {
+        Ok((version, feature_set)) => (
+            version.clone(),
+            feature_set.map_or_else(|| "-".to_string(), |set| set.to_string()),
+        ),
+        Err(_) => ("unavailable".to_string(), "-".to_string()),
+    }
+}
+
+/// Probes the configured RPC URL and every extra endpoint at once, then
+/// compares them so a lagging provider stands out
+async fn process_cluster_health(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let primary = ctx.rpc().url();
+    let mut endpoints = vec![primary.clone()];
+    for endpoint in ctx.rpc_endpoints() {
+        if !endpoints.contains(endpoint) {
+            endpoints.push(endpoint.clone());
+        }
+    }
+
+    let commitment = ctx.rpc().commitment();
+    let reports = futures::future::join_all(
+        endpoints
+            .iter()
+            .map(|endpoint| probe_endpoint(endpoint, commitment)),
+    )
+    .await;
+
+    let report = &reports[0];
+    let (version, feature_set) = version_text(report);
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Endpoint"), Cell::new(&report.url)])
+        .add_row(vec![Cell::new("Health"), health_cell(report)])
+        .add_row(vec![
+            Cell::new("Latency"),
+            Cell::new(format!("{} ms", report.latency.as_millis())),
+        ])
+        .add_row(vec![Cell::new("Solana Core"), Cell::new(version)])
+        .add_row(vec![Cell::new("Feature Set"), Cell::new(feature_set)])
+        .add_row(vec![
+            Cell::new("Processed Slot"),
+            slot_cell(&report.processed_slot),
+        ])
+        .add_row(vec![
+            Cell::new("Confirmed Slot"),
+            slot_cell(&report.confirmed_slot),
+        ])
+        .add_row(vec![
+            Cell::new("Finalized Slot"),
+            slot_cell(&report.finalized_slot),
+        ])
+        .add_row(vec![
+            Cell::new("First Available Block"),
+            slot_cell(&report.first_available_block),
+        ])
+        .add_row(vec![
+            Cell::new("Minimum Ledger Slot"),
+            slot_cell(&report.minimum_ledger_slot),
+        ]);
+
+    println!("\n{}", style("CLUSTER HEALTH").green().bold());
+    println!("{table}");
+
+    if reports.len() == 1 {
+        println!(
+            "{}",
+            style("Add RPC endpoints under ScillaConfig > Edit to compare providers").dim()
+        );
+        return Ok(());
+    }
+
+    let highest_slot = reports
+        .iter()
+        .filter_map(|report| report.processed_slot.as_ref().ok().copied())
+        .max()
+        .unwrap_or_default();
+    let (_, primary_feature_set) = version_text(&reports[0]);
+
+    let mut comparison = Table::new();
+    comparison.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Endpoint").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Health").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Latency").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Processed Slot").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Lag").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Version").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Feature Set").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for report in &reports {
+        let (version, feature_set) = version_text(report);
+        let lag = match report.slot_lag(highest_slot) {
+            Some(0) => Cell::new(style("0").green()),
+            Some(lag) => Cell::new(style(format!("{lag} slots")).yellow()),
+            None => Cell::new("-"),
+        };
+        let feature_set = if feature_set == primary_feature_set {
+            Cell::new(feature_set)
+        } else {
+            Cell::new(style(feature_set).yellow())
+        };
+
+        comparison.add_row(vec![
+            Cell::new(&report.url),
+            health_cell(report),
+            Cell::new(format!("{} ms", report.latency.as_millis())),
+            slot_cell(&report.processed_slot),
+            lag,
+            Cell::new(version),
+            feature_set,
+        ]);
+    }
+
+    println!("\n{}", style("ENDPOINT COMPARISON").green().bold());
+    println!("{comparison}");
+
+    match recommend_endpoint(&reports) {
+        Some(idx) => println!(
+            "{} {}",
+            style("Recommended:").green().bold(),
+            style(&reports[idx].url).cyan()
+        ),
+        None => println!("{}", style("No endpoint is healthy").red().bold()),
+    }
+
+    Ok(())
+}
+
+/// Everything one dashboard frame shows, read in a single round of requests
+struct DashboardSnapshot {
+    epoch_info: EpochInfo,
+    samples: Vec<RpcPerfSample>,
+    supply: RpcSupply,
+    vote_accounts: RpcVoteAccountStatus,
+    /// Only fetched when validators are configured
+    block_production: Option<RpcBlockProduction>,
+}
+
+async fn fetch_dashboard_snapshot(ctx: &ScillaContext) -> anyhow::Result<DashboardSnapshot> {
+    let (epoch_info, samples, supply, vote_accounts, block_production) = tokio::try_join!(
+        async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        async {
+            ctx.rpc()
+                .get_recent_performance_samples(Some(PERFORMANCE_SAMPLE_LIMIT))
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        async {
+            // Skipping the non-circulating account list keeps the refresh cheap
+            let supply: Response<RpcSupply> = ctx
+                .rpc()
+                .send(
+                    RpcRequest::GetSupply,
+                    json!([{
+                        "commitment": ctx.rpc().commitment().commitment,
+                        "excludeNonCirculatingAccountsList": true,
+                    }]),
+                )
+                .await?;
+            Ok(supply.value)
+        },
+        async {
+            ctx.rpc()
+                .get_vote_accounts()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        async {
+            if ctx.validators().is_empty() {
+                return Ok(None);
+            }
+            let production = ctx.rpc().get_block_production().await?;
+            Ok(Some(production.value))
+        },
+    )?;
+
+    Ok(DashboardSnapshot {
+        epoch_info,
+        samples,
+        supply,
+        vote_accounts,
+        block_production,
+    })
+}
+
+/// Redraws the dashboard every few seconds until Ctrl+C. A failed refresh
+/// keeps the last frame on screen with the error under it.
+async fn run_dashboard(ctx: &ScillaContext, names: &HashMap<Pubkey, String>) -> anyhow::Result<()> {
+    let term = Term::stdout();
+    let _cursor = HiddenCursor::new()?;
+
+    // Selecting on the signal alongside every fetch registers it before the
+    // first request goes out
+    let stop = tokio::signal::ctrl_c();
+    tokio::pin!(stop);
+
+    let mut last_snapshot = None;
+    loop {
+        let refresh = tokio::select! {
+            _ = &mut stop => break,
+            refresh = fetch_dashboard_snapshot(ctx) => refresh,
+        };
+        term.clear_screen()?;
+
+        let error = match refresh {
+            Ok(snapshot) => {
+                last_snapshot = Some((snapshot, chrono::Local::now()));
+                None
+            }
+            Err(err) => Some(err),
+        };
+        if let Some((snapshot, refreshed_at)) = &last_snapshot {
+            print_dashboard(ctx, snapshot, names, refreshed_at);
+        }
+        if let Some(err) = error {
+            println!("{}", style(format!("Refresh failed: {err}")).yellow());
+        }
+        println!(
+            "{}",
+            style(format!(
+                "Refreshing every {DASHBOARD_REFRESH_SECS}s, press Ctrl+C to exit"
+            ))
+            .dim()
+        );
+
+        tokio::select! {
+            _ = &mut stop => break,
+            _ = tokio::time::sleep(Duration::from_secs(DASHBOARD_REFRESH_SECS)) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn print_dashboard(
+    ctx: &ScillaContext,
+    snapshot: &DashboardSnapshot,
+    names: &HashMap<Pubkey, String>,
+    refreshed_at: &chrono::DateTime<chrono::Local>,
+) {
+    let epoch_info = &snapshot.epoch_info;
+    let ms_per_slot = average_slot_time_ms(&snapshot.samples);
+
+    println!(
+        "{} {}",
+        style("⚡ CLUSTER DASHBOARD").green().bold(),
+        style(format!(
+            "{} · {}",
+            ctx.rpc().url(),
+            refreshed_at.format("%H:%M:%S")
+        ))
+        .dim()
+    );
+
+    // Epoch progress with the boundary ETA
+    let progress = epoch_info.slot_index as f64 / epoch_info.slots_in_epoch as f64;
+    let slots_left = epoch_info.slots_in_epoch - epoch_info.slot_index;
+    let eta = ms_per_slot.map_or_else(
+        || "unknown".to_string(),
+        |ms_per_slot| {
+            let boundary = estimate_slot_datetime(
+                epoch_info.absolute_slot,
+                epoch_info.absolute_slot + slots_left,
+                ms_per_slot,
+            );
+            format!(
+                "{} ({})",
+                format_duration((slots_left as f64 * ms_per_slot / 1000.0) as u64),
+                boundary.format("%Y-%m-%d %H:%M UTC")
+            )
+        },
+    );
+    println!(
+        "\nEpoch {}  {} {:.2}%",
+        style(epoch_info.epoch).cyan().bold(),
+        progress_bar(progress, DASHBOARD_BAR_WIDTH),
+        progress * 100.0
+    );
+    println!(
+        "{}",
+        style(format!(
+            "Slot {} of {} · next epoch in {eta}",
+            epoch_info.slot_index, epoch_info.slots_in_epoch
+        ))
+        .dim()
+    );
+
+    let vote_accounts = &snapshot.vote_accounts;
+    let current_stake: u64 = vote_accounts
+        .current
+        .iter()
+        .map(|v| v.activated_stake)
+        .sum();
+    let delinquent_stake: u64 = vote_accounts
+        .delinquent
+        .iter()
+        .map(|v| v.activated_stake)
+        .sum();
+    let total_stake = current_stake + delinquent_stake;
+    let delinquent_pct = if total_stake == 0 {
+        0.0
+    } else {
+        delinquent_stake as f64 / total_stake as f64 * 100.0
+    };
+
+    let slot_rate = ms_per_slot.map_or_else(
+        || "-".to_string(),
+        |ms_per_slot| {
+            format!(
+                "{:.2} slots/s ({ms_per_slot:.0} ms/slot)",
+                1000.0 / ms_per_slot
+            )
+        },
+    );
+    let tps = match transactions_per_second(&snapshot.samples) {
+        Some((total, Some(non_vote))) => format!("{total:.0} ({non_vote:.0} non-vote)"),
+        Some((total, None)) => format!("{total:.0}"),
+        None => "-".to_string(),
+    };
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Slot"), Cell::new(epoch_info.absolute_slot)])
+        .add_row(vec![
+            Cell::new("Block Height"),
+            Cell::new(epoch_info.block_height),
+        ])
+        .add_row(vec![Cell::new("Slot Rate"), Cell::new(slot_rate)])
+        .add_row(vec![Cell::new("TPS"), Cell::new(tps)])
+        .add_row(vec![
+            Cell::new("Circulating Supply"),
+            Cell::new(format!(
+                "{:.0} of {:.0} SOL",
+                lamports_to_sol(snapshot.supply.circulating),
+                lamports_to_sol(snapshot.supply.total)
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Validators"),
+            Cell::new(format!(
+                "{} current, {} delinquent",
+                vote_accounts.current.len(),
+                vote_accounts.delinquent.len()
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Delinquent Stake"),
+            Cell::new(format!(
+                "{:.0} SOL ({delinquent_pct:.2}%)",
+                lamports_to_sol(delinquent_stake)
+            )),
+        ]);
+    println!("{table}");
+
+    if ctx.validators().is_empty() {
+        println!(
+            "{}",
+            style("Add validators under ScillaConfig > Edit to follow them here").dim()
+        );
+        return;
+    }
+
+    let mut validators_table = Table::new();
+    validators_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Validator").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Last Vote").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Behind").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Root").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Skip Rate").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for validator in ctx.validators() {
+        let validator = validator.to_string();
+        let matches = |info: &&RpcVoteAccountInfo| {
+            info.vote_pubkey == validator || info.node_pubkey == validator
+        };
+        let (info, status) = match vote_accounts.current.iter().find(matches) {
+            Some(info) => (info, style("Voting").green()),
+            None => match vote_accounts.delinquent.iter().find(matches) {
+                Some(info) => (info, style("Delinquent").red()),
+                None => {
+                    validators_table.add_row(vec![
+                        Cell::new(&validator),
+                        Cell::new(style("Not found").yellow()),
+                        Cell::new("-"),
+                        Cell::new("-"),
+                        Cell::new("-"),
+                        Cell::new("-"),
+                    ]);
+                    continue;
+                }
+            },
+        };
+
+        let skip_rate = snapshot
+            .block_production
+            .as_ref()
+            .and_then(|production| production.by_identity.get(&info.node_pubkey))
+            .filter(|(leader_slots, _)| *leader_slots > 0)
+            .map_or_else(
+                || "no leader slots yet".to_string(),
+                |(leader_slots, produced)| {
+                    format!(
+                        "{:.2}% ({produced}/{leader_slots})",
+                        (leader_slots - produced) as f64 / *leader_slots as f64 * 100.0
+                    )
+                },
+            );
+
+        validators_table.add_row(vec![
+            Cell::new(format!(
+                "{}\n{}",
+                validator_name(names, &info.node_pubkey),
+                info.vote_pubkey
+            )),
+            Cell::new(status),
+            Cell::new(info.last_vote),
+            Cell::new(epoch_info.absolute_slot.saturating_sub(info.last_vote)),
+            Cell::new(info.root_slot),
+            Cell::new(skip_rate),
+        ]);
+    }
+
+    println!("\n{}", style("OUR VALIDATORS").green().bold());
+    println!("{validators_table}");
+}
@@ -3,7 +3,8 @@ use {
         commands::CommandExec,
         config::{ScillaConfig, scilla_config_path},
         error::ScillaResult,
-        prompt::prompt_data,
+        misc::helpers::{PubkeyList, UrlList},
+        prompt::{prompt_data, prompt_optional_data},
     },
     anyhow::Ok,
     comfy_table::{Cell, Table, presets::UTF8_FULL},
@@ -11,6 +12,7 @@ use {
     inquire::{Confirm, Select},
     serde::{Deserialize, Serialize},
     solana_commitment_config::CommitmentLevel,
+    solana_pubkey::Pubkey,
     std::{fmt, fs, path::PathBuf},
 };
 
@@ -51,6 +53,8 @@ enum ConfigField {
     RpcUrl,
     CommitmentLevel,
     KeypairPath,
+    Validators,
+    RpcEndpoints,
     None, // if None is chosen , we go back to previous context
 }
 
@@ -60,6 +64,8 @@ impl fmt::Display for ConfigField {
             ConfigField::RpcUrl => write!(f, "RPC URL"),
             ConfigField::CommitmentLevel => write!(f, "Commitment Level"),
             ConfigField::KeypairPath => write!(f, "Keypair Path"),
+            ConfigField::Validators => write!(f, "Dashboard Validators"),
+            ConfigField::RpcEndpoints => write!(f, "Compared RPC Endpoints"),
             ConfigField::None => write!(f, "None"),
         }
     }
@@ -71,6 +77,8 @@ impl ConfigField {
             ConfigField::RpcUrl,
             ConfigField::CommitmentLevel,
             ConfigField::KeypairPath,
+            ConfigField::Validators,
+            ConfigField::RpcEndpoints,
             ConfigField::None,
         ]
     }
@@ -119,6 +127,24 @@ impl ConfigCommand {
     }
 }
 
+fn format_validators(validators: &[Pubkey]) -> String {
+    if validators.is_empty() {
+        return "None".to_string();
+    }
+    validators
+        .iter()
+        .map(Pubkey::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_rpc_endpoints(endpoints: &[String]) -> String {
+    if endpoints.is_empty() {
+        return "None".to_string();
+    }
+    endpoints.join("\n")
+}
+
 fn show_config() -> anyhow::Result<()> {
     let config = ScillaConfig::load()?;
 
@@ -137,6 +163,14 @@ fn show_config() -> anyhow::Result<()> {
         .add_row(vec![
             Cell::new("Keypair Path"),
             Cell::new(config.keypair_path.display()),
+        ])
+        .add_row(vec![
+            Cell::new("Dashboard Validators"),
+            Cell::new(format_validators(&config.validators)),
+        ])
+        .add_row(vec![
+            Cell::new("Compared RPC Endpoints"),
+            Cell::new(format_rpc_endpoints(&config.rpc_endpoints)),
         ]);
 
     println!("\n{}", style("SCILLA CONFIG").green().bold());
@@ -220,6 +254,8 @@ pub fn generate_config() -> anyhow::Result<()> {
             rpc_url,
             commitment_level,
             keypair_path,
+            validators: Vec::new(),
+            rpc_endpoints: Vec::new(),
         }
     };
 
@@ -261,6 +297,16 @@ fn edit_config() -> anyhow::Result<()> {
         style("Current Keypair Path:").cyan(),
         config.keypair_path.display()
     );
+    println!(
+        "{} {}",
+        style("Current Dashboard Validators:").cyan(),
+        format_validators(&config.validators)
+    );
+    println!(
+        "{} {}",
+        style("Current Compared RPC Endpoints:").cyan(),
+        format_rpc_endpoints(&config.rpc_endpoints)
+    );
 
     // Prompt user to select which field to edit
     let field_options = ConfigField::all();
@@ -311,6 +357,19 @@ fn edit_config() -> anyhow::Result<()> {
                 break;
             }
         }
+        ConfigField::Validators => {
+            let validators: Option<PubkeyList> = prompt_optional_data(
+                "Enter vote accounts or identities (comma or space separated, leave empty to \
+                 clear):",
+            )?;
+            config.validators = validators.map_or_else(Vec::new, |list| list.pubkeys().to_vec());
+        }
+        ConfigField::RpcEndpoints => {
+            let endpoints: Option<UrlList> = prompt_optional_data(
+                "Enter RPC URLs to compare (comma or space separated, leave empty to clear):",
+            )?;
+            config.rpc_endpoints = endpoints.map_or_else(Vec::new, |list| list.urls().to_vec());
+        }
         ConfigField::None => return Ok(()),
     }
 
@@ -0,0 +1,546 @@
+use {
+    crate::{
+        ScillaContext, ScillaResult,
+        commands::CommandExec,
+        constants::LOOKUP_TABLE_EXTEND_CHUNK_SIZE,
+        misc::{
+            helpers::{PubkeyList, build_and_send_tx, fetch_lookup_table},
+            programs::known_program_name,
+        },
+        prompt::{prompt_authority_keypair, prompt_data, prompt_optional_data},
+        ui::show_spinner,
+    },
+    anyhow::bail,
+    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    console::style,
+    solana_address_lookup_table_interface::{
+        instruction::{
+            close_lookup_table, create_lookup_table, deactivate_lookup_table, extend_lookup_table,
+            freeze_lookup_table,
+        },
+        state::{AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES, estimate_last_valid_slot},
+    },
+    solana_commitment_config::CommitmentConfig,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+    std::fmt,
+};
+
+/// Commands for managing address lookup tables
+#[derive(Debug, Clone)]
+pub enum LookupTableCommand {
+    Create,
+    Extend,
+    Freeze,
+    Deactivate,
+    Close,
+    Show,
+    UseForTransactions,
+    GoBack,
+}
+
+impl LookupTableCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            LookupTableCommand::Create => "Creating lookup table…",
+            LookupTableCommand::Extend => "Extending lookup table…",
+            LookupTableCommand::Freeze => "Freezing lookup table…",
+            LookupTableCommand::Deactivate => "Deactivating lookup table…",
+            LookupTableCommand::Close => "Closing lookup table…",
+            LookupTableCommand::Show => "Fetching lookup table…",
+            LookupTableCommand::UseForTransactions => "Loading lookup table…",
+            LookupTableCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for LookupTableCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            LookupTableCommand::Create => "Create lookup table",
+            LookupTableCommand::Extend => "Extend lookup table",
+            LookupTableCommand::Freeze => "Freeze lookup table",
+            LookupTableCommand::Deactivate => "Deactivate lookup table",
+            LookupTableCommand::Close => "Close lookup table",
+            LookupTableCommand::Show => "Show lookup table",
+            LookupTableCommand::UseForTransactions => "Use for outgoing transactions",
+            LookupTableCommand::GoBack => "Go back",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl LookupTableCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            LookupTableCommand::Create => {
+                // Creating a table doesn't need the authority's signature
+                let authority: Option<Pubkey> = prompt_optional_data(
+                    "Enter authority address (leave empty to use your wallet):",
+                )?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_create_lookup_table(ctx, authority),
+                )
+                .await?;
+            }
+            LookupTableCommand::Extend => {
+                let lookup_table: Pubkey = prompt_data("Enter Lookup Table Address:")?;
+                let addresses: PubkeyList =
+                    prompt_data("Enter addresses to add (comma or space separated):")?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_extend_lookup_table(
+                        ctx,
+                        &lookup_table,
+                        addresses.pubkeys(),
+                        authority.as_ref(),
+                    ),
+                )
+                .await?;
+            }
+            LookupTableCommand::Freeze => {
+                let lookup_table: Pubkey = prompt_data("Enter Lookup Table Address:")?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_freeze_lookup_table(ctx, &lookup_table, authority.as_ref()),
+                )
+                .await?;
+            }
+            LookupTableCommand::Deactivate => {
+                let lookup_table: Pubkey = prompt_data("Enter Lookup Table Address:")?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_deactivate_lookup_table(ctx, &lookup_table, authority.as_ref()),
+                )
+                .await?;
+            }
+            LookupTableCommand::Close => {
+                let lookup_table: Pubkey = prompt_data("Enter Lookup Table Address:")?;
+                let recipient: Option<Pubkey> = prompt_optional_data(
+                    "Enter Recipient Address (leave empty for the configured wallet):",
+                )?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_close_lookup_table(
+                        ctx,
+                        &lookup_table,
+                        &recipient.unwrap_or(*ctx.pubkey()),
+                        authority.as_ref(),
+                    ),
+                )
+                .await?;
+            }
+            LookupTableCommand::Show => {
+                let lookup_table: Pubkey = prompt_data("Enter Lookup Table Address:")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_show_lookup_table(ctx, &lookup_table),
+                )
+                .await?;
+            }
+            LookupTableCommand::UseForTransactions => {
+                if let Some(current) = ctx.lookup_table() {
+                    println!(
+                        "{} {}",
+                        style("Currently compiling v0 transactions against:").dim(),
+                        style(current).cyan()
+                    );
+                }
+
+                let lookup_table: Option<Pubkey> = prompt_optional_data(
+                    "Enter Lookup Table Address (leave empty to send legacy transactions):",
+                )?;
+
+                match lookup_table {
+                    Some(lookup_table) => {
+                        show_spinner(
+                            self.spinner_msg(),
+                            process_use_lookup_table(ctx, &lookup_table),
+                        )
+                        .await?;
+                    }
+                    None => {
+                        ctx.set_lookup_table(None);
+                        println!(
+                            "{}",
+                            style("Outgoing transactions will use legacy messages").green()
+                        );
+                    }
+                }
+            }
+            LookupTableCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// Fetches the table and checks `authority` may still modify it
+async fn fetch_lookup_table_for_authority(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+    authority: &Pubkey,
+) -> anyhow::Result<AddressLookupTable<'static>> {
+    let table = fetch_lookup_table(ctx, lookup_table).await?;
+
+    match table.meta.authority {
+        None => bail!("Lookup table {lookup_table} is frozen"),
+        Some(current) if current != *authority => {
+            bail!("{authority} is not the authority of {lookup_table} (authority is {current})")
+        }
+        Some(_) => Ok(table),
+    }
+}
+
+async fn process_create_lookup_table(
+    ctx: &ScillaContext,
+    authority: Option<Pubkey>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.unwrap_or(*ctx.pubkey());
+
+    // The derivation slot must be one the runtime still has a hash for
+    let recent_slot = ctx
+        .rpc()
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .await?;
+
+    let (create_ix, lookup_table) =
+        create_lookup_table(authority_pubkey, *ctx.pubkey(), recent_slot);
+
+    let signature = build_and_send_tx(ctx, &[create_ix], &[ctx.keypair()]).await?;
+
+    println!(
+        "{} {}",
+        style("Lookup table created:").green().bold(),
+        style(lookup_table).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_extend_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+    addresses: &[Pubkey],
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let table = fetch_lookup_table_for_authority(ctx, lookup_table, &authority_pubkey).await?;
+
+    if table.meta.deactivation_slot != u64::MAX {
+        bail!("Lookup table {lookup_table} is deactivated and can't be extended");
+    }
+
+    let mut new_addresses: Vec<Pubkey> = Vec::new();
+    for address in addresses {
+        if !table.addresses.contains(address) && !new_addresses.contains(address) {
+            new_addresses.push(*address);
+        }
+    }
+
+    if new_addresses.is_empty() {
+        bail!("All addresses are already in lookup table {lookup_table}");
+    }
+    if table.addresses.len() + new_addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+        bail!(
+            "Lookup table {lookup_table} holds {} addresses; adding {} would exceed the limit of \
+             {LOOKUP_TABLE_MAX_ADDRESSES}",
+            table.addresses.len(),
+            new_addresses.len()
+        );
+    }
+
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    for chunk in new_addresses.chunks(LOOKUP_TABLE_EXTEND_CHUNK_SIZE) {
+        let extend_ix = extend_lookup_table(
+            *lookup_table,
+            authority_pubkey,
+            Some(*ctx.pubkey()),
+            chunk.to_vec(),
+        );
+
+        let signature = build_and_send_tx(ctx, &[extend_ix], &signers).await?;
+
+        println!(
+            "{} {} {}",
+            style(format!("Added {} addresses.", chunk.len()))
+                .green()
+                .bold(),
+            style("Signature:").green().bold(),
+            style(signature).cyan()
+        );
+    }
+
+    println!(
+        "{} {}",
+        style("Lookup table now holds").green().bold(),
+        style(format!(
+            "{} addresses",
+            table.addresses.len() + new_addresses.len()
+        ))
+        .cyan()
+    );
+    println!(
+        "{}",
+        style("New addresses become usable from the next slot").dim()
+    );
+
+    Ok(())
+}
+
+async fn process_freeze_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let table = fetch_lookup_table_for_authority(ctx, lookup_table, &authority_pubkey).await?;
+
+    if table.addresses.is_empty() {
+        bail!("Empty lookup tables can't be frozen");
+    }
+
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let freeze_ix = freeze_lookup_table(*lookup_table, authority_pubkey);
+    let signature = build_and_send_tx(ctx, &[freeze_ix], &signers).await?;
+
+    println!(
+        "{} {}",
+        style("Lookup table frozen:").green().bold(),
+        style(lookup_table).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_deactivate_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let table = fetch_lookup_table_for_authority(ctx, lookup_table, &authority_pubkey).await?;
+
+    if table.meta.deactivation_slot != u64::MAX {
+        bail!(
+            "Lookup table {lookup_table} was already deactivated at slot {}",
+            table.meta.deactivation_slot
+        );
+    }
+
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let deactivate_ix = deactivate_lookup_table(*lookup_table, authority_pubkey);
+    let signature = build_and_send_tx(ctx, &[deactivate_ix], &signers).await?;
+
+    if ctx.lookup_table() == Some(*lookup_table) {
+        ctx.set_lookup_table(None);
+        println!(
+            "{}",
+            style("Outgoing transactions switched back to legacy messages").yellow()
+        );
+    }
+
+    println!(
+        "{} {}",
+        style("Lookup table deactivated:").green().bold(),
+        style(lookup_table).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_close_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+    recipient: &Pubkey,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let (table, current_slot) = tokio::try_join!(
+        fetch_lookup_table_for_authority(ctx, lookup_table, &authority_pubkey),
+        async { ctx.rpc().get_slot().await.map_err(anyhow::Error::from) }
+    )?;
+
+    if table.meta.deactivation_slot == u64::MAX {
+        bail!("Lookup table {lookup_table} must be deactivated before it can be closed");
+    }
+    let last_valid_slot = estimate_last_valid_slot(table.meta.deactivation_slot);
+    if current_slot <= last_valid_slot {
+        bail!(
+            "Lookup table {lookup_table} is still deactivating; it can be closed after slot \
+             {last_valid_slot} (current slot {current_slot})"
+        );
+    }
+
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let close_ix = close_lookup_table(*lookup_table, authority_pubkey, *recipient);
+    let signature = build_and_send_tx(ctx, &[close_ix], &signers).await?;
+
+    println!(
+        "{} {}",
+        style("Lookup table closed, rent sent to").green().bold(),
+        style(recipient).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_show_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+) -> anyhow::Result<()> {
+    let (table, current_slot) = tokio::try_join!(fetch_lookup_table(ctx, lookup_table), async {
+        ctx.rpc().get_slot().await.map_err(anyhow::Error::from)
+    })?;
+
+    let status = if table.meta.deactivation_slot == u64::MAX {
+        style("Active".to_string()).green()
+    } else {
+        let last_valid_slot = estimate_last_valid_slot(table.meta.deactivation_slot);
+        if current_slot > last_valid_slot {
+            style("Deactivated (can be closed)".to_string()).red()
+        } else {
+            style(format!(
+                "Deactivating (closable after slot {last_valid_slot})"
+            ))
+            .yellow()
+        }
+    };
+
+    let mut table_info = Table::new();
+    table_info
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Address"), Cell::new(lookup_table)])
+        .add_row(vec![
+            Cell::new("Authority"),
+            Cell::new(
+                table
+                    .meta
+                    .authority
+                    .map(|authority| authority.to_string())
+                    .unwrap_or_else(|| "None (frozen)".to_string()),
+            ),
+        ])
+        .add_row(vec![Cell::new("Status"), Cell::new(status)])
+        .add_row(vec![
+            Cell::new("Last Extended Slot"),
+            Cell::new(table.meta.last_extended_slot),
+        ])
+        .add_row(vec![
+            Cell::new("Addresses"),
+            Cell::new(format!(
+                "{} / {LOOKUP_TABLE_MAX_ADDRESSES}",
+                table.addresses.len()
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Used For Outgoing Transactions"),
+            Cell::new(if ctx.lookup_table() == Some(*lookup_table) {
+                "✓"
+            } else {
+                ""
+            }),
+        ]);
+
+    println!("\n{}", style("ADDRESS LOOKUP TABLE").green().bold());
+    println!("{table_info}");
+
+    if !table.addresses.is_empty() {
+        let mut addresses_table = Table::new();
+        addresses_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Index").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Program").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (idx, address) in table.addresses.iter().enumerate() {
+            addresses_table.add_row(vec![
+                Cell::new(idx),
+                Cell::new(address),
+                Cell::new(known_program_name(address).unwrap_or("")),
+            ]);
+        }
+
+        println!("\n{}", style("ADDRESSES").cyan().bold());
+        println!("{addresses_table}");
+    }
+
+    Ok(())
+}
+
+async fn process_use_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+) -> anyhow::Result<()> {
+    let table = fetch_lookup_table(ctx, lookup_table).await?;
+
+    if table.meta.deactivation_slot != u64::MAX {
+        bail!("Lookup table {lookup_table} is deactivated and can't be used");
+    }
+    if table.addresses.is_empty() {
+        bail!("Lookup table {lookup_table} is empty; extend it first");
+    }
+
+    ctx.set_lookup_table(Some(*lookup_table));
+
+    println!(
+        "{} {}",
+        style("Outgoing transactions will be compiled as v0 messages using")
+            .green()
+            .bold(),
+        style(lookup_table).cyan()
+    );
+
+    Ok(())
+}
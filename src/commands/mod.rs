@@ -2,7 +2,9 @@ use {
     crate::{
         commands::{
             account::AccountCommand, cluster::ClusterCommand, config::ConfigCommand,
-            stake::StakeCommand, transaction::TransactionCommand, vote::VoteCommand,
+            lookup_table::LookupTableCommand, program::ProgramCommand, stake::StakeCommand,
+            sysvar::SysvarCommand, token::TokenCommand, transaction::TransactionCommand,
+            vote::VoteCommand,
         },
         context::ScillaContext,
         error::ScillaResult,
@@ -17,7 +19,11 @@ use {
 pub mod account;
 pub mod cluster;
 pub mod config;
+pub mod lookup_table;
+pub mod program;
 pub mod stake;
+pub mod sysvar;
+pub mod token;
 pub mod transaction;
 pub mod vote;
 
@@ -37,10 +43,14 @@ impl<T> Termination for CommandExec<T> {
 #[derive(Debug, Clone)]
 pub enum Command {
     Cluster(ClusterCommand),
+    Sysvar(SysvarCommand),
     Stake(StakeCommand),
     Account(AccountCommand),
     Vote(VoteCommand),
     Transaction(TransactionCommand),
+    Token(TokenCommand),
+    Program(ProgramCommand),
+    LookupTable(LookupTableCommand),
     ScillaConfig(ConfigCommand),
     Exit,
 }
@@ -49,12 +59,18 @@ impl Command {
     pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
         match self {
             Command::Cluster(cluster_command) => cluster_command.process_command(ctx).await,
+            Command::Sysvar(sysvar_command) => sysvar_command.process_command(ctx).await,
             Command::Stake(stake_command) => stake_command.process_command(ctx).await,
             Command::Account(account_command) => account_command.process_command(ctx).await,
             Command::Vote(vote_command) => vote_command.process_command(ctx).await,
             Command::Transaction(transaction_command) => {
                 transaction_command.process_command(ctx).await
             }
+            Command::Token(token_command) => token_command.process_command(ctx).await,
+            Command::Program(program_command) => program_command.process_command(ctx).await,
+            Command::LookupTable(lookup_table_command) => {
+                lookup_table_command.process_command(ctx).await
+            }
             Command::ScillaConfig(config_command) => config_command.process_command(),
             Command::Exit => Ok(CommandExec::Exit),
         }
@@ -65,9 +81,13 @@ impl Command {
 pub enum CommandGroup {
     Account,
     Cluster,
+    Sysvar,
     Stake,
     Vote,
     Transaction,
+    Token,
+    Program,
+    LookupTable,
     ScillaConfig,
     Exit,
 }
@@ -77,9 +97,13 @@ impl fmt::Display for CommandGroup {
         let command = match self {
             CommandGroup::Account => "Account",
             CommandGroup::Cluster => "Cluster",
+            CommandGroup::Sysvar => "Sysvar",
             CommandGroup::Stake => "Stake",
             CommandGroup::Vote => "Vote",
             CommandGroup::Transaction => "Transaction",
+            CommandGroup::Token => "Token",
+            CommandGroup::Program => "Program",
+            CommandGroup::LookupTable => "Lookup Table",
             CommandGroup::ScillaConfig => "ScillaConfig",
             CommandGroup::Exit => "Exit",
         };
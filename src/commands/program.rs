@@ -0,0 +1,1095 @@
+use {
+    crate::{
+        ScillaContext, ScillaResult,
+        commands::CommandExec,
+        config::expand_tilde,
+        constants::{
+            CONFIRMATION_POLL_INTERVAL_MS, PROGRAM_WRITE_MAX_ROUNDS, PROGRAM_WRITE_PARALLELISM,
+        },
+        misc::{
+            batch::pack_instructions,
+            helpers::{
+                bincode_deserialize, build_and_send_tx, lamports_to_sol, read_keypair_from_path,
+            },
+            loader::{
+                buffer_program_data, check_program_elf, elf_hash, parse_loader_v4, pending_writes,
+                programdata_capacity, write_chunk_size,
+            },
+            programs::program_label,
+        },
+        prompt::{prompt_authority_keypair, prompt_data, prompt_optional_data},
+        ui::show_spinner,
+    },
+    anyhow::{Context, anyhow, bail},
+    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    console::style,
+    futures::{StreamExt, stream},
+    indicatif::{ProgressBar, ProgressStyle},
+    inquire::Confirm,
+    serde_json::json,
+    solana_account::Account,
+    solana_commitment_config::CommitmentConfig,
+    solana_instruction::Instruction,
+    solana_keypair::{EncodableKey, Keypair, Signer},
+    solana_loader_v3_interface::{
+        get_program_data_address,
+        instruction::{
+            close, create_buffer, extend_program_checked, set_upgrade_authority,
+            set_upgrade_authority_checked, upgrade, write,
+        },
+        state::UpgradeableLoaderState,
+    },
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{
+        config::RpcSendTransactionConfig, request::RpcRequest, response::RpcKeyedAccount,
+    },
+    solana_sdk_ids::{
+        bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, loader_v4, native_loader,
+    },
+    solana_transaction::Transaction,
+    std::{
+        fmt, fs,
+        path::{Path, PathBuf},
+        time::Duration,
+    },
+};
+
+/// Commands for deploying and managing programs owned by the upgradeable
+/// loader
+#[derive(Debug, Clone)]
+pub enum ProgramCommand {
+    Show,
+    Dump,
+    Deploy,
+    WriteBuffer,
+    Upgrade,
+    SetUpgradeAuthority,
+    Extend,
+    ListBuffers,
+    CloseBuffers,
+    GoBack,
+}
+
+impl ProgramCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            ProgramCommand::Show => "Fetching program…",
+            ProgramCommand::Dump => "Dumping program…",
+            ProgramCommand::Deploy => "Deploying program…",
+            ProgramCommand::WriteBuffer => "Writing program buffer…",
+            ProgramCommand::Upgrade => "Upgrading program…",
+            ProgramCommand::SetUpgradeAuthority => "Setting upgrade authority…",
+            ProgramCommand::Extend => "Extending program data…",
+            ProgramCommand::ListBuffers => "Fetching buffers…",
+            ProgramCommand::CloseBuffers => "Closing buffers…",
+            ProgramCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for ProgramCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            ProgramCommand::Show => "Show program",
+            ProgramCommand::Dump => "Dump program to file",
+            ProgramCommand::Deploy => "Deploy program",
+            ProgramCommand::WriteBuffer => "Write program to buffer",
+            ProgramCommand::Upgrade => "Upgrade program from buffer",
+            ProgramCommand::SetUpgradeAuthority => "Set or revoke upgrade authority",
+            ProgramCommand::Extend => "Extend program data",
+            ProgramCommand::ListBuffers => "List buffers",
+            ProgramCommand::CloseBuffers => "Close buffers",
+            ProgramCommand::GoBack => "Go back",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl ProgramCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            ProgramCommand::Show => {
+                let program: Pubkey = prompt_data("Enter Program Id:")?;
+
+                show_spinner(self.spinner_msg(), process_show_program(ctx, &program)).await?;
+            }
+            ProgramCommand::Dump => {
+                let program: Pubkey = prompt_data("Enter Program Id:")?;
+                let path: Option<String> = prompt_optional_data(
+                    "Enter output file path (leave empty for <program id>.so):",
+                )?;
+                let path = path.map_or_else(
+                    || PathBuf::from(format!("{program}.so")),
+                    |path| expand_tilde(&path),
+                );
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_dump_program(ctx, &program, &path),
+                )
+                .await?;
+            }
+            ProgramCommand::Deploy => {
+                let path: String = prompt_data("Enter program file path (.so):")?;
+                let program_keypair: Option<PathBuf> = prompt_optional_data(
+                    "Enter Program Keypair Path (leave empty for <name>-keypair.json next to the \
+                     program, generated if missing):",
+                )?;
+                let buffer_keypair: Option<PathBuf> = prompt_optional_data(
+                    "Enter Buffer Keypair Path (leave empty for <name>-buffer-keypair.json, \
+                     resumed if it exists):",
+                )?;
+
+                process_deploy_program(
+                    ctx,
+                    &expand_tilde(&path),
+                    program_keypair.as_deref(),
+                    buffer_keypair.as_deref(),
+                )
+                .await?;
+            }
+            ProgramCommand::WriteBuffer => {
+                let path: String = prompt_data("Enter program file path (.so):")?;
+                let buffer_keypair: Option<PathBuf> = prompt_optional_data(
+                    "Enter Buffer Keypair Path (leave empty for <name>-buffer-keypair.json, \
+                     resumed if it exists):",
+                )?;
+                let authority = prompt_authority_keypair()?;
+
+                process_write_buffer(
+                    ctx,
+                    &expand_tilde(&path),
+                    buffer_keypair.as_deref(),
+                    authority.as_ref(),
+                )
+                .await?;
+            }
+            ProgramCommand::Upgrade => {
+                let program: Pubkey = prompt_data("Enter Program Id:")?;
+                let buffer: Pubkey = prompt_data("Enter Buffer Address:")?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_upgrade_program(ctx, &program, &buffer, authority.as_ref()),
+                )
+                .await?;
+            }
+            ProgramCommand::SetUpgradeAuthority => {
+                let program: Pubkey = prompt_data("Enter Program Id:")?;
+                let new_authority_path: Option<PathBuf> = prompt_optional_data(
+                    "Enter New Upgrade Authority Keypair Path (leave empty to make the program \
+                     immutable):",
+                )?;
+                let new_authority = new_authority_path.map(read_keypair_from_path).transpose()?;
+                let authority = prompt_authority_keypair()?;
+
+                if new_authority.is_none()
+                    && !Confirm::new(
+                        "Revoking the upgrade authority is permanent; the program can never be \
+                         upgraded again. Continue?",
+                    )
+                    .with_default(false)
+                    .prompt()?
+                {
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_set_upgrade_authority(
+                        ctx,
+                        &program,
+                        new_authority.as_ref(),
+                        authority.as_ref(),
+                    ),
+                )
+                .await?;
+            }
+            ProgramCommand::Extend => {
+                let program: Pubkey = prompt_data("Enter Program Id:")?;
+                let additional_bytes: u32 = prompt_data("Enter number of bytes to add:")?;
+                let authority = prompt_authority_keypair()?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_extend_program(ctx, &program, additional_bytes, authority.as_ref()),
+                )
+                .await?;
+            }
+            ProgramCommand::ListBuffers => {
+                let authority: Option<Pubkey> = prompt_optional_data(
+                    "Enter Authority Address (leave empty for the configured wallet):",
+                )?;
+                let authority = authority.unwrap_or(*ctx.pubkey());
+
+                let buffers = show_spinner(
+                    self.spinner_msg(),
+                    fetch_buffers_of_authority(ctx, &authority),
+                )
+                .await?;
+                print_buffers(&authority, &buffers);
+            }
+            ProgramCommand::CloseBuffers => {
+                let buffer: Option<Pubkey> = prompt_optional_data(
+                    "Enter Buffer Address (leave empty for every buffer of the authority):",
+                )?;
+                let authority = prompt_authority_keypair()?;
+                let authority_pubkey = authority
+                    .as_ref()
+                    .map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+
+                let buffers = match buffer {
+                    Some(buffer) => vec![buffer],
+                    None => {
+                        let buffers = show_spinner(
+                            "Fetching buffers…",
+                            fetch_buffers_of_authority(ctx, &authority_pubkey),
+                        )
+                        .await?;
+                        if !print_buffers(&authority_pubkey, &buffers)
+                            || !Confirm::new("Close these buffers?")
+                                .with_default(false)
+                                .prompt()?
+                        {
+                            return Ok(CommandExec::Process(()));
+                        }
+                        buffers.into_iter().map(|(buffer, _)| buffer).collect()
+                    }
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_close_buffers(ctx, &buffers, authority.as_ref()),
+                )
+                .await?;
+            }
+            ProgramCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// Program id and upgrade state of a deployed program
+struct DeployedProgram {
+    programdata_address: Pubkey,
+    programdata: Account,
+    last_deployed_slot: u64,
+    upgrade_authority: Option<Pubkey>,
+}
+
+async fn fetch_loader_account(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+) -> anyhow::Result<Option<(Account, UpgradeableLoaderState)>> {
+    let Some(account) = ctx
+        .rpc()
+        .get_account_with_commitment(address, ctx.rpc().commitment())
+        .await?
+        .value
+    else {
+        return Ok(None);
+    };
+    if account.owner != bpf_loader_upgradeable::id() {
+        bail!("{address} is not owned by the upgradeable loader");
+    }
+    let state = bincode_deserialize(&account.data, "upgradeable loader state")?;
+
+    Ok(Some((account, state)))
+}
+
+async fn fetch_deployed_program(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+) -> anyhow::Result<DeployedProgram> {
+    let Some((
+        _,
+        UpgradeableLoaderState::Program {
+            programdata_address,
+        },
+    )) = fetch_loader_account(ctx, program).await?
+    else {
+        bail!("{program} is not an upgradeable program");
+    };
+
+    let Some((
+        programdata,
+        UpgradeableLoaderState::ProgramData {
+            slot,
+            upgrade_authority_address,
+        },
+    )) = fetch_loader_account(ctx, &programdata_address).await?
+    else {
+        bail!("Program data account {programdata_address} of {program} is missing");
+    };
+
+    Ok(DeployedProgram {
+        programdata_address,
+        programdata,
+        last_deployed_slot: slot,
+        upgrade_authority: upgrade_authority_address,
+    })
+}
+
+/// Fetches the program and checks `authority` may still upgrade it
+async fn fetch_program_for_authority(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+    authority: &Pubkey,
+) -> anyhow::Result<DeployedProgram> {
+    let deployed = fetch_deployed_program(ctx, program).await?;
+
+    match deployed.upgrade_authority {
+        None => bail!("Program {program} is immutable"),
+        Some(current) if current != *authority => {
+            bail!("{authority} is not the upgrade authority of {program} (authority is {current})")
+        }
+        Some(_) => Ok(deployed),
+    }
+}
+
+/// Where a program's code lives and who may change it, whichever loader owns
+/// it
+struct ProgramDetails {
+    loader: Pubkey,
+    programdata_address: Option<Pubkey>,
+    upgrade_authority: Option<Pubkey>,
+    status: Option<&'static str>,
+    last_deployed_slot: Option<u64>,
+    elf: Vec<u8>,
+    /// Held by the program account and its program data account
+    lamports: u64,
+}
+
+async fn fetch_program_details(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+) -> anyhow::Result<ProgramDetails> {
+    let account = ctx
+        .rpc()
+        .get_account(program)
+        .await
+        .map_err(|_| anyhow!("Program {program} does not exist"))?;
+
+    let details = match account.owner {
+        owner if owner == bpf_loader_upgradeable::id() => {
+            let deployed = fetch_deployed_program(ctx, program).await?;
+            ProgramDetails {
+                loader: owner,
+                programdata_address: Some(deployed.programdata_address),
+                upgrade_authority: deployed.upgrade_authority,
+                status: None,
+                last_deployed_slot: Some(deployed.last_deployed_slot),
+                elf: deployed.programdata.data
+                    [UpgradeableLoaderState::size_of_programdata_metadata()..]
+                    .to_vec(),
+                lamports: account.lamports + deployed.programdata.lamports,
+            }
+        }
+        owner if owner == bpf_loader::id() || owner == bpf_loader_deprecated::id() => {
+            ProgramDetails {
+                loader: owner,
+                programdata_address: None,
+                upgrade_authority: None,
+                status: None,
+                last_deployed_slot: None,
+                elf: account.data,
+                lamports: account.lamports,
+            }
+        }
+        owner if owner == loader_v4::id() => {
+            let (header, elf) = parse_loader_v4(&account.data)?;
+            // A finalized program stores its next version in the authority slot
+            let upgrade_authority =
+                (header.status != "Finalized").then_some(header.authority_address_or_next_version);
+            ProgramDetails {
+                loader: owner,
+                programdata_address: None,
+                upgrade_authority,
+                status: Some(header.status),
+                last_deployed_slot: Some(header.slot),
+                elf: elf.to_vec(),
+                lamports: account.lamports,
+            }
+        }
+        owner if owner == native_loader::id() => {
+            bail!("{program} is a builtin program without an on-chain ELF")
+        }
+        _ => bail!("{program} is not a program (owned by {})", account.owner),
+    };
+
+    Ok(details)
+}
+
+async fn process_show_program(ctx: &ScillaContext, program: &Pubkey) -> anyhow::Result<()> {
+    let details = fetch_program_details(ctx, program).await?;
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Program Id"), Cell::new(program)])
+        .add_row(vec![
+            Cell::new("Loader"),
+            Cell::new(program_label(&details.loader)),
+        ]);
+    if let Some(status) = details.status {
+        table.add_row(vec![Cell::new("Status"), Cell::new(status)]);
+    }
+    table.add_row(vec![
+        Cell::new("Upgrade Authority"),
+        Cell::new(details.upgrade_authority.map_or_else(
+            || "None (immutable)".to_string(),
+            |authority| authority.to_string(),
+        )),
+    ]);
+    if let Some(programdata_address) = details.programdata_address {
+        table.add_row(vec![
+            Cell::new("Program Data"),
+            Cell::new(programdata_address),
+        ]);
+    }
+    if let Some(slot) = details.last_deployed_slot {
+        table.add_row(vec![Cell::new("Last Deployed Slot"), Cell::new(slot)]);
+    }
+    table
+        .add_row(vec![
+            Cell::new("Data Length"),
+            Cell::new(format!("{} bytes", details.elf.len())),
+        ])
+        .add_row(vec![
+            Cell::new("Rent"),
+            Cell::new(format!("{} SOL", lamports_to_sol(details.lamports))),
+        ])
+        .add_row(vec![
+            Cell::new("Program Hash (trailing zeros stripped)"),
+            Cell::new(elf_hash(&details.elf)),
+        ]);
+
+    println!("\n{}", style("PROGRAM").green().bold());
+    println!("{table}");
+
+    Ok(())
+}
+
+async fn process_dump_program(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+    path: &Path,
+) -> anyhow::Result<()> {
+    let details = fetch_program_details(ctx, program).await?;
+
+    fs::write(path, &details.elf).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!(
+        "{} {}",
+        style("Program written to:").green().bold(),
+        style(path.display()).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Size:").green().bold(),
+        style(format!("{} bytes", details.elf.len())).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Program Hash (trailing zeros stripped):")
+            .green()
+            .bold(),
+        style(elf_hash(&details.elf)).cyan()
+    );
+
+    Ok(())
+}
+
+/// Reads the program file and checks it is an ELF shared object
+fn read_program_file(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let data = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    check_program_elf(&data)?;
+    Ok(data)
+}
+
+/// `<name><suffix>` next to the program file
+fn sibling_path(program_path: &Path, suffix: &str) -> anyhow::Result<PathBuf> {
+    let stem = program_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| anyhow!("Invalid program file name: {}", program_path.display()))?;
+    Ok(program_path.with_file_name(format!("{stem}{suffix}")))
+}
+
+/// Reads the keypair at `path`, generating and saving a new one if the file
+/// does not exist yet
+fn read_or_create_keypair(path: &Path) -> anyhow::Result<Keypair> {
+    if path.exists() {
+        return read_keypair_from_path(path);
+    }
+
+    let keypair = Keypair::new();
+    keypair
+        .write_to_file(path)
+        .map_err(|e| anyhow!("Failed to write keypair to {}: {}", path.display(), e))?;
+    println!(
+        "{} {}",
+        style("Saved new keypair to").dim(),
+        style(path.display()).cyan()
+    );
+    Ok(keypair)
+}
+
+fn progress_style() -> anyhow::Result<ProgressStyle> {
+    Ok(
+        ProgressStyle::with_template("{bar:40.cyan/blue} {pos}/{len} chunks {msg}")?
+            .progress_chars("█▓░"),
+    )
+}
+
+/// Creates `buffer` if needed and writes the chunks of `program_data` it does
+/// not hold yet, so an interrupted upload resumes where it stopped
+async fn upload_program(
+    ctx: &ScillaContext,
+    program_data: &[u8],
+    buffer: &Keypair,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let buffer_len = UpgradeableLoaderState::size_of_buffer(program_data.len());
+
+    let existing = show_spinner(
+        "Checking buffer…",
+        fetch_loader_account(ctx, &buffer.pubkey()),
+    )
+    .await?;
+
+    let written = match existing {
+        Some((account, UpgradeableLoaderState::Buffer { authority_address })) => {
+            if authority_address != Some(authority_pubkey) {
+                bail!(
+                    "{authority_pubkey} is not the authority of buffer {}",
+                    buffer.pubkey()
+                );
+            }
+            if account.data.len() != buffer_len {
+                bail!(
+                    "Buffer {} holds a program of {} bytes, this one is {} bytes",
+                    buffer.pubkey(),
+                    buffer_program_data(&account.data).len(),
+                    program_data.len()
+                );
+            }
+            println!(
+                "{} {}",
+                style("Resuming upload to buffer").dim(),
+                style(buffer.pubkey()).cyan()
+            );
+            buffer_program_data(&account.data).to_vec()
+        }
+        Some(_) => bail!("{} is not a program buffer", buffer.pubkey()),
+        None => {
+            show_spinner("Creating buffer…", async {
+                let lamports = ctx
+                    .rpc()
+                    .get_minimum_balance_for_rent_exemption(buffer_len)
+                    .await?;
+                let create_ixs = create_buffer(
+                    ctx.pubkey(),
+                    &buffer.pubkey(),
+                    &authority_pubkey,
+                    lamports,
+                    program_data.len(),
+                )?;
+                build_and_send_tx(ctx, &create_ixs, &[ctx.keypair(), buffer]).await
+            })
+            .await?;
+            Vec::new()
+        }
+    };
+
+    let chunk_size = write_chunk_size(ctx.pubkey(), &buffer.pubkey(), &authority_pubkey);
+    let pending = pending_writes(program_data, &written, chunk_size);
+    write_chunks(ctx, &buffer.pubkey(), authority, pending).await
+}
+
+/// Sends buffer writes in parallel and re-sends those that did not land
+/// before their blockhash expired
+async fn write_chunks(
+    ctx: &ScillaContext,
+    buffer: &Pubkey,
+    authority: Option<&Keypair>,
+    mut pending: Vec<(u32, &[u8])>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let progress = ProgressBar::new(pending.len() as u64);
+    progress.set_style(progress_style()?);
+
+    // Writes are idempotent, so re-sending one that landed late is harmless
+    let send_config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..Default::default()
+    };
+
+    for round in 1..=PROGRAM_WRITE_MAX_ROUNDS {
+        if pending.is_empty() {
+            break;
+        }
+        progress.set_message(format!("(round {round})"));
+
+        let blockhash = ctx.rpc().get_latest_blockhash().await?;
+        let transactions: Vec<Transaction> = pending
+            .iter()
+            .map(|(offset, bytes)| {
+                let write_ix = write(buffer, &authority_pubkey, *offset, bytes.to_vec());
+                Transaction::new_signed_with_payer(
+                    &[write_ix],
+                    Some(ctx.pubkey()),
+                    &signers,
+                    blockhash,
+                )
+            })
+            .collect();
+
+        // Failed sends are picked up by the status polling below
+        stream::iter(&transactions)
+            .map(|tx| ctx.rpc().send_transaction_with_config(tx, send_config))
+            .buffer_unordered(PROGRAM_WRITE_PARALLELISM)
+            .collect::<Vec<_>>()
+            .await;
+
+        let signatures: Vec<_> = transactions.iter().map(|tx| tx.signatures[0]).collect();
+        let mut landed = vec![false; signatures.len()];
+        loop {
+            for (chunk_idx, chunk) in signatures.chunks(256).enumerate() {
+                let statuses = ctx.rpc().get_signature_statuses(chunk).await?.value;
+                for (idx, status) in statuses.into_iter().enumerate() {
+                    let idx = chunk_idx * 256 + idx;
+                    let Some(status) = status else { continue };
+                    if let Some(err) = &status.err {
+                        bail!("Write at offset {} failed: {err}", pending[idx].0);
+                    }
+                    if !landed[idx] && status.satisfies_commitment(ctx.rpc().commitment()) {
+                        landed[idx] = true;
+                        progress.inc(1);
+                    }
+                }
+            }
+
+            if landed.iter().all(|landed| *landed)
+                || !ctx
+                    .rpc()
+                    .is_blockhash_valid(&blockhash, CommitmentConfig::processed())
+                    .await?
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(CONFIRMATION_POLL_INTERVAL_MS)).await;
+        }
+
+        pending = pending
+            .into_iter()
+            .zip(landed)
+            .filter(|(_, landed)| !landed)
+            .map(|(write, _)| write)
+            .collect();
+    }
+
+    if !pending.is_empty() {
+        progress.abandon_with_message("incomplete");
+        bail!(
+            "{} chunks did not land after {PROGRAM_WRITE_MAX_ROUNDS} rounds; run the command \
+             again with the same buffer keypair to resume",
+            pending.len()
+        );
+    }
+    progress.finish_with_message("done");
+
+    Ok(())
+}
+
+async fn process_deploy_program(
+    ctx: &ScillaContext,
+    path: &Path,
+    program_keypair: Option<&Path>,
+    buffer_keypair: Option<&Path>,
+) -> anyhow::Result<()> {
+    let program_data = read_program_file(path)?;
+
+    let program_keypair_path = match program_keypair {
+        Some(path) => path.to_path_buf(),
+        None => sibling_path(path, "-keypair.json")?,
+    };
+    let program_keypair = read_or_create_keypair(&program_keypair_path)?;
+    let program_id = program_keypair.pubkey();
+
+    if ctx
+        .rpc()
+        .get_account_with_commitment(&program_id, ctx.rpc().commitment())
+        .await?
+        .value
+        .is_some()
+    {
+        bail!("Program {program_id} is already deployed; write a buffer and upgrade it instead");
+    }
+
+    let buffer_keypair_path = match buffer_keypair {
+        Some(path) => path.to_path_buf(),
+        None => sibling_path(path, "-buffer-keypair.json")?,
+    };
+    let buffer_keypair = read_or_create_keypair(&buffer_keypair_path)?;
+
+    upload_program(ctx, &program_data, &buffer_keypair, None).await?;
+
+    let signature = show_spinner("Deploying program…", async {
+        let program_lamports = ctx
+            .rpc()
+            .get_minimum_balance_for_rent_exemption(UpgradeableLoaderState::size_of_program())
+            .await?;
+        // Loader v4 is not enabled on every cluster yet, so deploy through v3
+        #[allow(deprecated)]
+        let deploy_ixs = solana_loader_v3_interface::instruction::deploy_with_max_program_len(
+            ctx.pubkey(),
+            &program_id,
+            &buffer_keypair.pubkey(),
+            ctx.pubkey(),
+            program_lamports,
+            program_data.len(),
+        )?;
+        build_and_send_tx(ctx, &deploy_ixs, &[ctx.keypair(), &program_keypair]).await
+    })
+    .await?;
+
+    // The buffer is consumed by the deploy; its keypair is no longer needed
+    let _ = fs::remove_file(&buffer_keypair_path);
+
+    println!(
+        "{} {}",
+        style("Program deployed:").green().bold(),
+        style(program_id).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Program data:").green().bold(),
+        style(get_program_data_address(&program_id)).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_write_buffer(
+    ctx: &ScillaContext,
+    path: &Path,
+    buffer_keypair: Option<&Path>,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let program_data = read_program_file(path)?;
+
+    let buffer_keypair_path = match buffer_keypair {
+        Some(path) => path.to_path_buf(),
+        None => sibling_path(path, "-buffer-keypair.json")?,
+    };
+    let buffer_keypair = read_or_create_keypair(&buffer_keypair_path)?;
+
+    upload_program(ctx, &program_data, &buffer_keypair, authority).await?;
+
+    println!(
+        "{} {}",
+        style("Buffer written:").green().bold(),
+        style(buffer_keypair.pubkey()).cyan()
+    );
+    println!(
+        "{}",
+        style("Use it with Upgrade program from buffer, or close it to reclaim its rent").dim()
+    );
+
+    Ok(())
+}
+
+async fn process_upgrade_program(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+    buffer: &Pubkey,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let deployed = fetch_program_for_authority(ctx, program, &authority_pubkey).await?;
+
+    let Some((buffer_account, UpgradeableLoaderState::Buffer { authority_address })) =
+        fetch_loader_account(ctx, buffer).await?
+    else {
+        bail!("{buffer} is not a program buffer");
+    };
+    if authority_address != Some(authority_pubkey) {
+        bail!(
+            "Buffer {buffer} must have the upgrade authority {authority_pubkey} as its authority"
+        );
+    }
+
+    let new_len = buffer_program_data(&buffer_account.data).len();
+    let capacity = programdata_capacity(deployed.programdata.data.len());
+    if new_len > capacity {
+        bail!(
+            "The new program is {new_len} bytes but {} only has room for {capacity}; extend it by \
+             at least {} bytes first",
+            deployed.programdata_address,
+            new_len - capacity
+        );
+    }
+
+    let upgrade_ix = upgrade(program, buffer, &authority_pubkey, ctx.pubkey());
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let signature = build_and_send_tx(ctx, &[upgrade_ix], &signers).await?;
+
+    println!(
+        "{} {}",
+        style("Program upgraded:").green().bold(),
+        style(program).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_set_upgrade_authority(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+    new_authority: Option<&Keypair>,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    fetch_program_for_authority(ctx, program, &authority_pubkey).await?;
+
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+    // The checked variant makes the new authority co-sign, so a mistyped key
+    // cannot lock the program; revoking has no new key and stays unchecked
+    let set_ix = match new_authority {
+        Some(new_authority) => {
+            signers.push(new_authority);
+            set_upgrade_authority_checked(program, &authority_pubkey, &new_authority.pubkey())
+        }
+        None => set_upgrade_authority(program, &authority_pubkey, None),
+    };
+
+    let signature = build_and_send_tx(ctx, &[set_ix], &signers).await?;
+
+    match new_authority {
+        Some(new_authority) => println!(
+            "{} {}",
+            style("Upgrade authority set to:").green().bold(),
+            style(new_authority.pubkey()).cyan()
+        ),
+        None => println!(
+            "{} {}",
+            style("Program is now immutable:").green().bold(),
+            style(program).cyan()
+        ),
+    }
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_extend_program(
+    ctx: &ScillaContext,
+    program: &Pubkey,
+    additional_bytes: u32,
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    if additional_bytes == 0 {
+        bail!("Number of bytes to add must be greater than zero");
+    }
+
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+    let deployed = fetch_program_for_authority(ctx, program, &authority_pubkey).await?;
+
+    let new_len = deployed.programdata.data.len() + additional_bytes as usize;
+    let rent = ctx
+        .rpc()
+        .get_minimum_balance_for_rent_exemption(new_len)
+        .await?
+        .saturating_sub(deployed.programdata.lamports);
+
+    let extend_ix = extend_program_checked(
+        program,
+        &authority_pubkey,
+        Some(ctx.pubkey()),
+        additional_bytes,
+    );
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    let signature = build_and_send_tx(ctx, &[extend_ix], &signers).await?;
+
+    println!(
+        "{} {}",
+        style("Program data extended:").green().bold(),
+        style(format!(
+            "{} now has room for {} bytes ({} SOL of rent paid)",
+            deployed.programdata_address,
+            programdata_capacity(new_len),
+            lamports_to_sol(rent)
+        ))
+        .cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+/// Buffers whose authority is `authority`, with their balances
+async fn fetch_buffers_of_authority(
+    ctx: &ScillaContext,
+    authority: &Pubkey,
+) -> anyhow::Result<Vec<(Pubkey, u64)>> {
+    // A buffer starts with its serialized state, so matching the whole
+    // metadata selects buffers of this authority; the data itself is skipped
+    let metadata = bincode::serialize(&UpgradeableLoaderState::Buffer {
+        authority_address: Some(*authority),
+    })?;
+
+    let keyed_accounts: Vec<RpcKeyedAccount> = ctx
+        .rpc()
+        .send(
+            RpcRequest::GetProgramAccounts,
+            json!([
+                bpf_loader_upgradeable::id().to_string(),
+                {
+                    "encoding": "base64",
+                    "commitment": ctx.rpc().commitment().commitment,
+                    "dataSlice": { "offset": 0, "length": 0 },
+                    "filters": [{
+                        "memcmp": { "offset": 0, "bytes": bs58::encode(metadata).into_string() },
+                    }],
+                },
+            ]),
+        )
+        .await?;
+
+    let mut buffers = keyed_accounts
+        .into_iter()
+        .map(|keyed| Ok((keyed.pubkey.parse()?, keyed.account.lamports)))
+        .collect::<anyhow::Result<Vec<(Pubkey, u64)>>>()?;
+    buffers.sort();
+
+    Ok(buffers)
+}
+
+/// Prints the buffers found for `authority`; returns whether there are any
+fn print_buffers(authority: &Pubkey, buffers: &[(Pubkey, u64)]) -> bool {
+    if buffers.is_empty() {
+        println!(
+            "{}",
+            style(format!("{authority} has no program buffers")).yellow()
+        );
+        return false;
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Buffer").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (buffer, lamports) in buffers {
+        table.add_row(vec![
+            Cell::new(buffer),
+            Cell::new(lamports_to_sol(*lamports)),
+        ]);
+    }
+    let total: u64 = buffers.iter().map(|(_, lamports)| lamports).sum();
+
+    println!("\n{}", style("PROGRAM BUFFERS").green().bold());
+    println!("{table}");
+    println!(
+        "{}",
+        style(format!(
+            "{} buffers holding {} SOL",
+            buffers.len(),
+            lamports_to_sol(total)
+        ))
+        .dim()
+    );
+    true
+}
+
+async fn process_close_buffers(
+    ctx: &ScillaContext,
+    buffers: &[Pubkey],
+    authority: Option<&Keypair>,
+) -> anyhow::Result<()> {
+    let authority_pubkey = authority.map_or(*ctx.pubkey(), |keypair| keypair.pubkey());
+
+    let mut reclaimed = 0;
+    for buffer in buffers {
+        let Some((account, UpgradeableLoaderState::Buffer { authority_address })) =
+            fetch_loader_account(ctx, buffer).await?
+        else {
+            bail!("{buffer} is not a program buffer");
+        };
+        if authority_address != Some(authority_pubkey) {
+            bail!("{authority_pubkey} is not the authority of buffer {buffer}");
+        }
+        reclaimed += account.lamports;
+    }
+
+    let close_ixs: Vec<Instruction> = buffers
+        .iter()
+        .map(|buffer| close(buffer, ctx.pubkey(), &authority_pubkey))
+        .collect();
+    let mut signers: Vec<&dyn Signer> = vec![ctx.keypair()];
+    if let Some(authority) = authority {
+        signers.push(authority);
+    }
+
+    for batch in pack_instructions(ctx.pubkey(), &close_ixs) {
+        let batch_ixs: Vec<Instruction> = batch.iter().map(|idx| close_ixs[*idx].clone()).collect();
+        let signature = build_and_send_tx(ctx, &batch_ixs, &signers).await?;
+        println!(
+            "{} {}",
+            style(format!("Closed {} buffers:", batch.len()))
+                .green()
+                .bold(),
+            style(signature).cyan()
+        );
+    }
+
+    println!(
+        "{} {}",
+        style("Reclaimed:").green().bold(),
+        style(format!("{} SOL", lamports_to_sol(reclaimed))).cyan()
+    );
+
+    Ok(())
+}
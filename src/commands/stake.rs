@@ -1,19 +1,27 @@
 use {
     crate::{
         commands::CommandExec,
-        constants::{ACTIVE_STAKE_EPOCH_BOUND, DEFAULT_EPOCH_LIMIT, STAKE_HISTORY_SYSVAR_ADDR},
+        constants::{
+            ACTIVE_STAKE_EPOCH_BOUND, DEFAULT_EPOCH_LIMIT, FORECAST_MAX_EPOCHS, LAMPORTS_PER_SOL,
+            REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID, STAKE_CHART_WIDTH, STAKE_HISTORY_SYSVAR_ADDR,
+        },
         context::ScillaContext,
         error::ScillaResult,
-        misc::helpers::{
-            SolAmount, bincode_deserialize, bincode_deserialize_with_limit, build_and_send_tx,
-            fetch_account_with_epoch, lamports_to_sol, read_keypair_from_path, sol_to_lamports,
+        misc::{
+            helpers::{
+                SolAmount, bincode_deserialize, bincode_deserialize_with_limit, build_and_send_tx,
+                estimate_slot_datetime, fetch_account_with_epoch, fetch_average_slot_time_ms,
+                lamports_to_sol, read_keypair_from_path, sol_to_lamports,
+            },
+            stake::{bar_chart, forecast_delegation, stake_history_rows},
         },
-        prompt::prompt_data,
+        prompt::{prompt_data, prompt_optional_data},
         ui::show_spinner,
     },
     anyhow::bail,
     comfy_table::{Cell, Table, presets::UTF8_FULL},
     console::style,
+    solana_epoch_schedule::EpochSchedule,
     solana_keypair::Signer,
     solana_pubkey::Pubkey,
     solana_stake_interface::{
@@ -36,6 +44,7 @@ pub enum StakeCommand {
     Split,
     Show,
     History,
+    Forecast,
     GoBack,
 }
 
@@ -50,6 +59,7 @@ impl StakeCommand {
             StakeCommand::Split => "Splitting stake into multiple accounts…",
             StakeCommand::Show => "Fetching stake account details…",
             StakeCommand::History => "Fetching stake account history…",
+            StakeCommand::Forecast => "Simulating warmup/cooldown…",
             StakeCommand::GoBack => "Going back…",
         }
     }
@@ -66,6 +76,7 @@ impl fmt::Display for StakeCommand {
             StakeCommand::Split => "Split stake account",
             StakeCommand::Show => "Show stake",
             StakeCommand::History => "View stake history",
+            StakeCommand::Forecast => "Forecast activation/deactivation",
             StakeCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -139,7 +150,26 @@ impl StakeCommand {
             }
             StakeCommand::Show => todo!(),
             StakeCommand::History => {
-                show_spinner(self.spinner_msg(), process_stake_history(ctx)).await?;
+                let from_epoch: Option<u64> = prompt_optional_data(&format!(
+                    "From epoch (leave empty for the last {DEFAULT_EPOCH_LIMIT} epochs):"
+                ))?;
+                let to_epoch: Option<u64> =
+                    prompt_optional_data("To epoch (leave empty for the latest):")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_stake_history(ctx, from_epoch, to_epoch),
+                )
+                .await?;
+            }
+            StakeCommand::Forecast => {
+                let stake_pubkey: Pubkey = prompt_data("Enter Stake Account Pubkey:")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_stake_forecast(ctx, &stake_pubkey),
+                )
+                .await?;
             }
 
             StakeCommand::GoBack => return Ok(CommandExec::GoBack),
@@ -473,44 +503,238 @@ async fn process_split_stake(
     Ok(())
 }
 
-async fn process_stake_history(ctx: &ScillaContext) -> anyhow::Result<()> {
+pub async fn fetch_stake_history(ctx: &ScillaContext) -> anyhow::Result<StakeHistory> {
     let stake_history_sysvar = Pubkey::from_str_const(STAKE_HISTORY_SYSVAR_ADDR);
 
     let account = ctx.rpc().get_account(&stake_history_sysvar).await?;
 
-    let stake_history: StakeHistory =
-        bincode_deserialize_with_limit(account.data.len() as u64, &account.data, "stake history")?;
+    bincode_deserialize_with_limit(account.data.len() as u64, &account.data, "stake history")
+}
+
+/// Epoch from which the reduced 9% warmup/cooldown rate applies, or `None`
+/// when the cluster hasn't activated it
+pub async fn fetch_new_rate_activation_epoch(
+    ctx: &ScillaContext,
+    epoch_schedule: &EpochSchedule,
+) -> anyhow::Result<Option<u64>> {
+    let feature_id = Pubkey::from_str_const(REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID);
+    let Some(account) = ctx
+        .rpc()
+        .get_account_with_commitment(&feature_id, ctx.rpc().commitment())
+        .await?
+        .value
+    else {
+        return Ok(None);
+    };
+
+    // Feature accounts hold the slot they were activated at, if any
+    let activated_at: Option<u64> = bincode_deserialize(&account.data, "feature account")?;
+    Ok(activated_at.map(|slot| epoch_schedule.get_epoch(slot)))
+}
+
+async fn process_stake_history(
+    ctx: &ScillaContext,
+    from_epoch: Option<u64>,
+    to_epoch: Option<u64>,
+) -> anyhow::Result<()> {
+    let stake_history = fetch_stake_history(ctx).await?;
 
     if stake_history.is_empty() {
         println!("\n{}", style("No stake history available").yellow());
         return Ok(());
     }
 
+    let rows = stake_history_rows(&stake_history, from_epoch, to_epoch, DEFAULT_EPOCH_LIMIT);
+    if rows.is_empty() {
+        let (newest, _) = &stake_history[0];
+        let (oldest, _) = &stake_history[stake_history.len() - 1];
+        bail!("No stake history in that range; the sysvar covers epochs {oldest} to {newest}");
+    }
+
+    let signed_sol = |lamports: i128| {
+        let sol = lamports as f64 / LAMPORTS_PER_SOL as f64;
+        format!("{sol:+.2}")
+    };
+
     let mut table = Table::new();
     table.load_preset(UTF8_FULL).set_header(vec![
         Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
         Cell::new("Effective Stake").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Δ Effective").add_attribute(comfy_table::Attribute::Bold),
         Cell::new("Activating Stake").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Warmup %").add_attribute(comfy_table::Attribute::Bold),
         Cell::new("Deactivating Stake").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Cooldown %").add_attribute(comfy_table::Attribute::Bold),
     ]);
 
-    for (epoch, entry) in stake_history.iter().take(DEFAULT_EPOCH_LIMIT) {
+    for row in &rows {
         let StakeHistoryEntry {
             effective,
             activating,
             deactivating,
-        } = entry;
+        } = row.entry;
 
         table.add_row(vec![
-            Cell::new(epoch),
-            Cell::new(lamports_to_sol(*effective)),
-            Cell::new(lamports_to_sol(*activating)),
-            Cell::new(lamports_to_sol(*deactivating)),
+            Cell::new(row.epoch),
+            Cell::new(format!("{:.2}", lamports_to_sol(effective))),
+            Cell::new(
+                row.effective_delta
+                    .map_or_else(|| "-".to_string(), signed_sol),
+            ),
+            Cell::new(format!("{:.2}", lamports_to_sol(activating))),
+            Cell::new(format!("{:.2}%", row.warmup_rate())),
+            Cell::new(format!("{:.2}", lamports_to_sol(deactivating))),
+            Cell::new(format!("{:.2}%", row.cooldown_rate())),
         ]);
     }
 
     println!("\n{}", style("CLUSTER STAKE HISTORY").green().bold());
     println!("{}", table);
 
+    let effective: Vec<u64> = rows.iter().map(|row| row.entry.effective).collect();
+    println!(
+        "\n{} {}",
+        style("EFFECTIVE STAKE").green().bold(),
+        style("(bars scaled from the lowest to the highest epoch shown)").dim()
+    );
+    for (row, bar) in rows.iter().zip(bar_chart(&effective, STAKE_CHART_WIDTH)) {
+        println!(
+            "{:>6} │{bar}│ {:.0} SOL",
+            row.epoch,
+            lamports_to_sol(row.entry.effective)
+        );
+    }
+
+    Ok(())
+}
+
+async fn process_stake_forecast(ctx: &ScillaContext, stake_pubkey: &Pubkey) -> anyhow::Result<()> {
+    let ((account, epoch_info), stake_history, epoch_schedule, ms_per_slot) = tokio::try_join!(
+        fetch_account_with_epoch(ctx, stake_pubkey),
+        fetch_stake_history(ctx),
+        async {
+            ctx.rpc()
+                .get_epoch_schedule()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        fetch_average_slot_time_ms(ctx),
+    )?;
+    let new_rate_activation_epoch = fetch_new_rate_activation_epoch(ctx, &epoch_schedule).await?;
+
+    if account.owner != stake_program_id() {
+        bail!("{stake_pubkey} is not a stake account");
+    }
+    let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
+    let StakeStateV2::Stake(meta, stake, _) = stake_state else {
+        bail!("Stake account {stake_pubkey} is not delegated");
+    };
+
+    let forecast = forecast_delegation(
+        &stake.delegation,
+        &stake_history,
+        epoch_info.epoch,
+        new_rate_activation_epoch,
+        FORECAST_MAX_EPOCHS,
+    );
+
+    let current_slot = epoch_info.absolute_slot;
+    let epoch_start = |epoch: u64| {
+        let slot = epoch_schedule.get_first_slot_in_epoch(epoch);
+        if slot <= current_slot {
+            return "current epoch".to_string();
+        }
+        estimate_slot_datetime(current_slot, slot, ms_per_slot)
+            .format("%Y-%m-%d %H:%M UTC")
+            .to_string()
+    };
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Starts (est.)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Effective (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Activating (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Deactivating (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for epoch in &forecast {
+        table.add_row(vec![
+            Cell::new(epoch.epoch),
+            Cell::new(epoch_start(epoch.epoch)),
+            Cell::new(format!("{:.4}", lamports_to_sol(epoch.effective))),
+            Cell::new(format!("{:.4}", lamports_to_sol(epoch.activating))),
+            Cell::new(format!("{:.4}", lamports_to_sol(epoch.deactivating))),
+        ]);
+    }
+
+    println!(
+        "\n{} {}",
+        style("STAKE FORECAST").green().bold(),
+        style(format!(
+            "({} SOL delegated to {})",
+            lamports_to_sol(stake.delegation.stake),
+            stake.delegation.voter_pubkey
+        ))
+        .dim()
+    );
+    println!("{table}");
+
+    let Some(settled) = forecast
+        .last()
+        .filter(|epoch| epoch.activating == 0 && epoch.deactivating == 0)
+    else {
+        println!(
+            "{}",
+            style(format!(
+                "Stake does not settle within {FORECAST_MAX_EPOCHS} epochs at the projected rates"
+            ))
+            .yellow()
+        );
+        return Ok(());
+    };
+
+    if settled.effective == 0 {
+        println!(
+            "{} {}",
+            style("Fully withdrawable from epoch").green().bold(),
+            style(format!(
+                "{} ({})",
+                settled.epoch,
+                epoch_start(settled.epoch)
+            ))
+            .cyan()
+        );
+        let lockup = &meta.lockup;
+        if lockup.epoch > settled.epoch || lockup.unix_timestamp > chrono::Utc::now().timestamp() {
+            println!(
+                "{}",
+                style(format!(
+                    "Lockup until epoch {} / unix time {} may delay withdrawal (custodian {})",
+                    lockup.epoch, lockup.unix_timestamp, lockup.custodian
+                ))
+                .yellow()
+            );
+        }
+    } else {
+        println!(
+            "{} {}",
+            style("Fully active from epoch").green().bold(),
+            style(format!(
+                "{} ({})",
+                settled.epoch,
+                epoch_start(settled.epoch)
+            ))
+            .cyan()
+        );
+    }
+    println!(
+        "{}",
+        style(
+            "Assumes no other stake activates or deactivates on the cluster; dates use the recent \
+             average slot time"
+        )
+        .dim()
+    );
+
     Ok(())
 }
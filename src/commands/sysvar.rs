@@ -0,0 +1,306 @@
+use {
+    crate::{
+        ScillaContext, ScillaResult,
+        commands::CommandExec,
+        constants::{DEFAULT_SLOT_HISTORY_WINDOW, DEFAULT_SYSVAR_ENTRIES},
+        misc::{
+            helpers::{bincode_deserialize_with_limit, lamports_to_sol},
+            sysvars::{decode_recent_blockhashes, recent_slot_window, sysvar_fields},
+        },
+        prompt::prompt_optional_data,
+        ui::show_spinner,
+    },
+    anyhow::anyhow,
+    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    console::style,
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::sysvar,
+    solana_slot_hashes::SlotHashes,
+    solana_slot_history::SlotHistory,
+    solana_stake_interface::stake_history::StakeHistory,
+    std::fmt,
+};
+
+/// Commands for reading and decoding sysvar accounts
+#[derive(Debug, Clone)]
+pub enum SysvarCommand {
+    Clock,
+    EpochSchedule,
+    Rent,
+    EpochRewards,
+    SlotHashes,
+    SlotHistory,
+    RecentBlockhashes,
+    LastRestartSlot,
+    StakeHistory,
+    GoBack,
+}
+
+impl SysvarCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            SysvarCommand::Clock => "Reading Clock sysvar…",
+            SysvarCommand::EpochSchedule => "Reading EpochSchedule sysvar…",
+            SysvarCommand::Rent => "Reading Rent sysvar…",
+            SysvarCommand::EpochRewards => "Reading EpochRewards sysvar…",
+            SysvarCommand::SlotHashes => "Reading SlotHashes sysvar…",
+            SysvarCommand::SlotHistory => "Reading SlotHistory sysvar…",
+            SysvarCommand::RecentBlockhashes => "Reading RecentBlockhashes sysvar…",
+            SysvarCommand::LastRestartSlot => "Reading LastRestartSlot sysvar…",
+            SysvarCommand::StakeHistory => "Reading StakeHistory sysvar…",
+            SysvarCommand::GoBack => "Going back…",
+        }
+    }
+
+    fn address(&self) -> Option<Pubkey> {
+        let address = match self {
+            SysvarCommand::Clock => sysvar::clock::id(),
+            SysvarCommand::EpochSchedule => sysvar::epoch_schedule::id(),
+            SysvarCommand::Rent => sysvar::rent::id(),
+            SysvarCommand::EpochRewards => sysvar::epoch_rewards::id(),
+            SysvarCommand::SlotHashes => sysvar::slot_hashes::id(),
+            SysvarCommand::SlotHistory => sysvar::slot_history::id(),
+            SysvarCommand::RecentBlockhashes => sysvar::recent_blockhashes::id(),
+            SysvarCommand::LastRestartSlot => sysvar::last_restart_slot::id(),
+            SysvarCommand::StakeHistory => sysvar::stake_history::id(),
+            SysvarCommand::GoBack => return None,
+        };
+        Some(address)
+    }
+}
+
+impl fmt::Display for SysvarCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            SysvarCommand::Clock => "Clock",
+            SysvarCommand::EpochSchedule => "Epoch Schedule",
+            SysvarCommand::Rent => "Rent",
+            SysvarCommand::EpochRewards => "Epoch Rewards",
+            SysvarCommand::SlotHashes => "Slot Hashes",
+            SysvarCommand::SlotHistory => "Slot History",
+            SysvarCommand::RecentBlockhashes => "Recent Blockhashes",
+            SysvarCommand::LastRestartSlot => "Last Restart Slot",
+            SysvarCommand::StakeHistory => "Stake History",
+            SysvarCommand::GoBack => "Go back",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl SysvarCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        let Some(address) = self.address() else {
+            return Ok(CommandExec::GoBack);
+        };
+
+        match self {
+            SysvarCommand::SlotHashes
+            | SysvarCommand::RecentBlockhashes
+            | SysvarCommand::StakeHistory => {
+                let limit: Option<usize> = prompt_optional_data(&format!(
+                    "Number of entries to show (leave empty for {DEFAULT_SYSVAR_ENTRIES}):"
+                ))?;
+                let limit = limit.unwrap_or(DEFAULT_SYSVAR_ENTRIES);
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_sysvar_entries(ctx, self, &address, limit),
+                )
+                .await?;
+            }
+            SysvarCommand::SlotHistory => {
+                let window: Option<u64> = prompt_optional_data(&format!(
+                    "Number of recent slots to inspect (leave empty for \
+                     {DEFAULT_SLOT_HISTORY_WINDOW}):"
+                ))?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_slot_history(ctx, window.unwrap_or(DEFAULT_SLOT_HISTORY_WINDOW)),
+                )
+                .await?;
+            }
+            _ => {
+                show_spinner(self.spinner_msg(), process_sysvar(ctx, self, &address)).await?;
+            }
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+async fn fetch_sysvar(ctx: &ScillaContext, address: &Pubkey) -> anyhow::Result<Account> {
+    ctx.rpc()
+        .get_account(address)
+        .await
+        .map_err(|_| anyhow!("Sysvar {address} is not available on this cluster"))
+}
+
+fn print_sysvar_header(sysvar: &SysvarCommand, address: &Pubkey, slot: u64) {
+    println!(
+        "\n{} {}",
+        style(sysvar.to_string().to_uppercase()).green().bold(),
+        style(format!("({address}, read at slot {slot})")).dim()
+    );
+}
+
+/// Sysvars holding a single struct, shown as a field/value table
+async fn process_sysvar(
+    ctx: &ScillaContext,
+    sysvar: &SysvarCommand,
+    address: &Pubkey,
+) -> anyhow::Result<()> {
+    let (account, slot) = tokio::try_join!(fetch_sysvar(ctx, address), async {
+        ctx.rpc().get_slot().await.map_err(anyhow::Error::from)
+    })?;
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (label, value) in sysvar_fields(address, &account.data)? {
+        table.add_row(vec![Cell::new(label), Cell::new(value)]);
+    }
+
+    print_sysvar_header(sysvar, address, slot);
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Sysvars holding a list, shown newest first up to `limit` entries
+async fn process_sysvar_entries(
+    ctx: &ScillaContext,
+    sysvar: &SysvarCommand,
+    address: &Pubkey,
+    limit: usize,
+) -> anyhow::Result<()> {
+    let (account, slot) = tokio::try_join!(fetch_sysvar(ctx, address), async {
+        ctx.rpc().get_slot().await.map_err(anyhow::Error::from)
+    })?;
+    let data = account.data.as_slice();
+
+    let header = |columns: &[&str]| {
+        columns
+            .iter()
+            .map(|column| Cell::new(column).add_attribute(comfy_table::Attribute::Bold))
+            .collect::<Vec<_>>()
+    };
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+
+    let total = match sysvar {
+        SysvarCommand::SlotHashes => {
+            let slot_hashes: SlotHashes =
+                bincode_deserialize_with_limit(data.len() as u64, data, "slot hashes sysvar")?;
+            table.set_header(header(&["Slot", "Bank Hash"]));
+            for (slot, hash) in slot_hashes.iter().take(limit) {
+                table.add_row(vec![Cell::new(slot), Cell::new(hash)]);
+            }
+            slot_hashes.len()
+        }
+        SysvarCommand::RecentBlockhashes => {
+            let entries = decode_recent_blockhashes(data)?;
+            table.set_header(header(&["#", "Blockhash", "Lamports per Signature"]));
+            for (idx, (blockhash, lamports_per_signature)) in entries.iter().take(limit).enumerate()
+            {
+                table.add_row(vec![
+                    Cell::new(idx),
+                    Cell::new(blockhash),
+                    Cell::new(lamports_per_signature),
+                ]);
+            }
+            entries.len()
+        }
+        SysvarCommand::StakeHistory => {
+            let stake_history: StakeHistory =
+                bincode_deserialize_with_limit(data.len() as u64, data, "stake history")?;
+            table.set_header(header(&[
+                "Epoch",
+                "Effective (SOL)",
+                "Activating (SOL)",
+                "Deactivating (SOL)",
+            ]));
+            for (epoch, entry) in stake_history.iter().take(limit) {
+                table.add_row(vec![
+                    Cell::new(epoch),
+                    Cell::new(format!("{:.2}", lamports_to_sol(entry.effective))),
+                    Cell::new(format!("{:.2}", lamports_to_sol(entry.activating))),
+                    Cell::new(format!("{:.2}", lamports_to_sol(entry.deactivating))),
+                ]);
+            }
+            stake_history.len()
+        }
+        _ => unreachable!("{sysvar} is not a list sysvar"),
+    };
+
+    print_sysvar_header(sysvar, address, slot);
+    println!("{table}");
+    println!(
+        "{}",
+        style(format!("Showing {} of {total} entries", limit.min(total))).dim()
+    );
+
+    Ok(())
+}
+
+async fn process_slot_history(ctx: &ScillaContext, window: u64) -> anyhow::Result<()> {
+    let address = sysvar::slot_history::id();
+    let account = fetch_sysvar(ctx, &address).await?;
+    let history: SlotHistory = bincode_deserialize_with_limit(
+        account.data.len() as u64,
+        &account.data,
+        "slot history sysvar",
+    )?;
+
+    let slots = recent_slot_window(&history, window);
+    let produced = slots.iter().filter(|(_, found)| *found).count();
+    let skipped = slots.len() - produced;
+    let skip_rate = if slots.is_empty() {
+        0.0
+    } else {
+        skipped as f64 / slots.len() as f64 * 100.0
+    };
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Newest Slot"), Cell::new(history.newest())])
+        .add_row(vec![
+            Cell::new("Oldest Tracked Slot"),
+            Cell::new(history.oldest()),
+        ])
+        .add_row(vec![Cell::new("Slots Inspected"), Cell::new(slots.len())])
+        .add_row(vec![Cell::new("Blocks Produced"), Cell::new(produced)])
+        .add_row(vec![Cell::new("Slots Skipped"), Cell::new(skipped)])
+        .add_row(vec![
+            Cell::new("Skip Rate"),
+            Cell::new(format!("{skip_rate:.2}%")),
+        ]);
+
+    print_sysvar_header(&SysvarCommand::SlotHistory, &address, history.newest());
+    println!("{table}");
+
+    // Oldest to newest so the strip reads left to right like a timeline
+    let strip: String = slots
+        .iter()
+        .rev()
+        .map(|(_, found)| if *found { '■' } else { '·' })
+        .collect();
+    println!(
+        "{}",
+        style("Recent slots (■ block, · skipped), oldest first:").dim()
+    );
+    for line in strip.chars().collect::<Vec<_>>().chunks(64) {
+        println!("{}", line.iter().collect::<String>());
+    }
+
+    Ok(())
+}
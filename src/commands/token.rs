@@ -0,0 +1,500 @@
+use {
+    crate::{
+        ScillaContext, ScillaResult,
+        commands::CommandExec,
+        constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+        misc::{
+            helpers::{build_and_send_tx, lamports_to_sol},
+            token::{
+                MintInfo, account_state_label, format_token_amount, parse_token_amount,
+                token_program_of, unpack_mint, unpack_token_account,
+            },
+        },
+        prompt::{prompt_data, prompt_optional_data},
+        ui::show_spinner,
+    },
+    anyhow::{anyhow, bail},
+    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    console::style,
+    serde_json::json,
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{
+        request::RpcRequest,
+        response::{Response, RpcKeyedAccount},
+    },
+    spl_associated_token_account_interface::{
+        address::get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account_idempotent,
+    },
+    spl_token_2022_interface::{
+        instruction::{close_account, transfer_checked},
+        state::AccountState,
+    },
+    std::{collections::HashMap, fmt},
+};
+
+/// Commands for SPL Token and Token-2022 accounts
+#[derive(Debug, Clone)]
+pub enum TokenCommand {
+    ListAccounts,
+    ShowMint,
+    CreateAssociatedAccount,
+    Transfer,
+    CloseAccount,
+    GoBack,
+}
+
+impl TokenCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            TokenCommand::ListAccounts => "Fetching token accounts…",
+            TokenCommand::ShowMint => "Fetching mint…",
+            TokenCommand::CreateAssociatedAccount => "Creating associated token account…",
+            TokenCommand::Transfer => "Transferring tokens…",
+            TokenCommand::CloseAccount => "Closing token account…",
+            TokenCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for TokenCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TokenCommand::ListAccounts => "List token accounts",
+            TokenCommand::ShowMint => "Show mint",
+            TokenCommand::CreateAssociatedAccount => "Create associated token account",
+            TokenCommand::Transfer => "Transfer tokens",
+            TokenCommand::CloseAccount => "Close empty token account",
+            TokenCommand::GoBack => "Go back",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl TokenCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            TokenCommand::ListAccounts => {
+                let owner: Option<Pubkey> = prompt_optional_data(
+                    "Enter Owner Address (leave empty for the configured wallet):",
+                )?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_list_token_accounts(ctx, &owner.unwrap_or(*ctx.pubkey())),
+                )
+                .await?;
+            }
+            TokenCommand::ShowMint => {
+                let mint: Pubkey = prompt_data("Enter Mint Address:")?;
+
+                show_spinner(self.spinner_msg(), process_show_mint(ctx, &mint)).await?;
+            }
+            TokenCommand::CreateAssociatedAccount => {
+                let mint: Pubkey = prompt_data("Enter Mint Address:")?;
+                let owner: Option<Pubkey> = prompt_optional_data(
+                    "Enter Owner Address (leave empty for the configured wallet):",
+                )?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_create_associated_account(ctx, &mint, &owner.unwrap_or(*ctx.pubkey())),
+                )
+                .await?;
+            }
+            TokenCommand::Transfer => {
+                let mint: Pubkey = prompt_data("Enter Mint Address:")?;
+                let recipient: Pubkey = prompt_data("Enter Recipient Wallet Address:")?;
+                let amount: String = prompt_data("Enter Amount (in tokens, e.g. 1.5):")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_transfer_tokens(ctx, &mint, &recipient, &amount),
+                )
+                .await?;
+            }
+            TokenCommand::CloseAccount => {
+                let token_account: Pubkey = prompt_data("Enter Token Account Address:")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_close_token_account(ctx, &token_account),
+                )
+                .await?;
+            }
+            TokenCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// Fetches `mint` and checks it belongs to one of the token programs
+async fn fetch_mint(ctx: &ScillaContext, mint: &Pubkey) -> anyhow::Result<(Pubkey, MintInfo)> {
+    let account = ctx
+        .rpc()
+        .get_account(mint)
+        .await
+        .map_err(|_| anyhow!("Mint {mint} does not exist"))?;
+    let program_id =
+        token_program_of(&account).ok_or_else(|| anyhow!("{mint} is not a token mint"))?;
+
+    Ok((program_id, unpack_mint(&account.data)?))
+}
+
+/// Token accounts of `owner` under a single token program
+async fn fetch_token_accounts_by_owner(
+    ctx: &ScillaContext,
+    owner: &Pubkey,
+    program_id: &str,
+) -> anyhow::Result<Vec<(Pubkey, Account)>> {
+    // The typed client call always asks for jsonParsed data; raw bytes let us
+    // decode Token-2022 extensions ourselves
+    let response: Response<Vec<RpcKeyedAccount>> = ctx
+        .rpc()
+        .send(
+            RpcRequest::GetTokenAccountsByOwner,
+            json!([
+                owner.to_string(),
+                { "programId": program_id },
+                { "encoding": "base64", "commitment": ctx.rpc().commitment().commitment },
+            ]),
+        )
+        .await?;
+
+    response
+        .value
+        .into_iter()
+        .map(|keyed| {
+            let pubkey: Pubkey = keyed.pubkey.parse()?;
+            let account = keyed
+                .account
+                .decode::<Account>()
+                .ok_or_else(|| anyhow!("Failed to decode token account {pubkey}"))?;
+            Ok((pubkey, account))
+        })
+        .collect()
+}
+
+async fn process_list_token_accounts(ctx: &ScillaContext, owner: &Pubkey) -> anyhow::Result<()> {
+    let (legacy, token_2022) = tokio::try_join!(
+        fetch_token_accounts_by_owner(ctx, owner, TOKEN_PROGRAM_ID),
+        fetch_token_accounts_by_owner(ctx, owner, TOKEN_2022_PROGRAM_ID),
+    )?;
+
+    let mut token_accounts = Vec::new();
+    for (pubkey, account) in legacy.into_iter().chain(token_2022) {
+        let program = if account.owner == Pubkey::from_str_const(TOKEN_PROGRAM_ID) {
+            "Token"
+        } else {
+            "Token-2022"
+        };
+        match unpack_token_account(&account.data) {
+            Ok(info) => token_accounts.push((pubkey, program, info)),
+            Err(e) => println!(
+                "{}",
+                style(format!("Skipping token account {pubkey}: {e}")).yellow()
+            ),
+        }
+    }
+
+    if token_accounts.is_empty() {
+        println!(
+            "{}",
+            style(format!("{owner} holds no token accounts")).yellow()
+        );
+        return Ok(());
+    }
+
+    let mut mints: Vec<Pubkey> = token_accounts
+        .iter()
+        .map(|(_, _, info)| info.account.mint)
+        .collect();
+    mints.sort();
+    mints.dedup();
+    let mut mint_accounts = HashMap::new();
+    // getMultipleAccounts accepts at most 100 keys per request
+    for chunk in mints.chunks(100) {
+        let fetched = ctx.rpc().get_multiple_accounts(chunk).await?;
+        mint_accounts.extend(
+            chunk
+                .iter()
+                .zip(fetched)
+                .filter_map(|(mint, account)| Some((*mint, account?))),
+        );
+    }
+
+    token_accounts.sort_by_key(|(pubkey, _, info)| (info.account.mint, *pubkey));
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Token Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Mint").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Balance").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("State").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Program").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (pubkey, program, info) in &token_accounts {
+        let decimals = mint_accounts
+            .get(&info.account.mint)
+            .and_then(|account| unpack_mint(&account.data).ok())
+            .map(|mint| mint.mint.decimals);
+        let balance = match decimals {
+            Some(decimals) => format_token_amount(info.account.amount, decimals),
+            None => format!("{} (raw)", info.account.amount),
+        };
+        let state = if info.account.is_native() {
+            format!("{} (native)", account_state_label(info.account.state))
+        } else {
+            account_state_label(info.account.state).to_string()
+        };
+
+        table.add_row(vec![
+            Cell::new(pubkey),
+            Cell::new(info.account.mint),
+            Cell::new(balance),
+            Cell::new(state),
+            Cell::new(program),
+        ]);
+    }
+
+    println!("\n{}", style("TOKEN ACCOUNTS").green().bold());
+    println!("{table}");
+    println!(
+        "{}",
+        style(format!(
+            "{} token accounts across {} mints",
+            token_accounts.len(),
+            mints.len()
+        ))
+        .dim()
+    );
+
+    Ok(())
+}
+
+async fn process_show_mint(ctx: &ScillaContext, mint: &Pubkey) -> anyhow::Result<()> {
+    let (program_id, info) = fetch_mint(ctx, mint).await?;
+
+    let authority = |authority: Option<Pubkey>| {
+        authority.map_or_else(|| "None".to_string(), |pubkey| pubkey.to_string())
+    };
+    let program = if program_id == Pubkey::from_str_const(TOKEN_PROGRAM_ID) {
+        "Token"
+    } else {
+        "Token-2022"
+    };
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Mint"), Cell::new(mint)])
+        .add_row(vec![Cell::new("Program"), Cell::new(program)])
+        .add_row(vec![
+            Cell::new("Supply"),
+            Cell::new(format_token_amount(info.mint.supply, info.mint.decimals)),
+        ])
+        .add_row(vec![Cell::new("Decimals"), Cell::new(info.mint.decimals)])
+        .add_row(vec![
+            Cell::new("Mint Authority"),
+            Cell::new(authority(info.mint.mint_authority.into())),
+        ])
+        .add_row(vec![
+            Cell::new("Freeze Authority"),
+            Cell::new(authority(info.mint.freeze_authority.into())),
+        ]);
+
+    if let Some(metadata) = &info.metadata {
+        table
+            .add_row(vec![Cell::new("Name"), Cell::new(&metadata.name)])
+            .add_row(vec![Cell::new("Symbol"), Cell::new(&metadata.symbol)])
+            .add_row(vec![Cell::new("URI"), Cell::new(&metadata.uri)]);
+    }
+
+    println!("\n{}", style("MINT").green().bold());
+    println!("{table}");
+
+    if !info.extensions.is_empty() {
+        let mut extensions = Table::new();
+        extensions.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Token-2022 Extension").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for extension in &info.extensions {
+            extensions.add_row(vec![Cell::new(format!("{extension:?}"))]);
+        }
+        println!("{extensions}");
+    }
+
+    Ok(())
+}
+
+async fn process_create_associated_account(
+    ctx: &ScillaContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> anyhow::Result<()> {
+    let (program_id, _) = fetch_mint(ctx, mint).await?;
+    let address = get_associated_token_address_with_program_id(owner, mint, &program_id);
+
+    if ctx.rpc().get_account(&address).await.is_ok() {
+        bail!("Associated token account {address} already exists");
+    }
+
+    let create_ix =
+        create_associated_token_account_idempotent(ctx.pubkey(), owner, mint, &program_id);
+    let signature = build_and_send_tx(ctx, &[create_ix], &[ctx.keypair()]).await?;
+
+    println!(
+        "{} {}",
+        style("Associated token account created:").green().bold(),
+        style(address).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_transfer_tokens(
+    ctx: &ScillaContext,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    amount: &str,
+) -> anyhow::Result<()> {
+    let (program_id, mint_info) = fetch_mint(ctx, mint).await?;
+    let decimals = mint_info.mint.decimals;
+    let amount = parse_token_amount(amount, decimals)?;
+
+    let source = get_associated_token_address_with_program_id(ctx.pubkey(), mint, &program_id);
+    let destination = get_associated_token_address_with_program_id(recipient, mint, &program_id);
+    if source == destination {
+        bail!("Source and destination token accounts are the same");
+    }
+
+    let mut accounts = ctx
+        .rpc()
+        .get_multiple_accounts(&[source, destination])
+        .await?
+        .into_iter();
+    let source_account = accounts
+        .next()
+        .flatten()
+        .ok_or_else(|| anyhow!("The configured wallet has no token account for mint {mint}"))?;
+    let destination_exists = accounts.next().flatten().is_some();
+    let source_info = unpack_token_account(&source_account.data)?;
+
+    if source_info.account.state == AccountState::Frozen {
+        bail!("Token account {source} is frozen");
+    }
+    if source_info.account.amount < amount {
+        bail!(
+            "Insufficient balance: {} available, {} requested",
+            format_token_amount(source_info.account.amount, decimals),
+            format_token_amount(amount, decimals)
+        );
+    }
+
+    let mut instructions = Vec::new();
+    if !destination_exists {
+        instructions.push(create_associated_token_account_idempotent(
+            ctx.pubkey(),
+            recipient,
+            mint,
+            &program_id,
+        ));
+    }
+    instructions.push(transfer_checked(
+        &program_id,
+        &source,
+        mint,
+        &destination,
+        ctx.pubkey(),
+        &[],
+        amount,
+        decimals,
+    )?);
+
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.keypair()]).await?;
+
+    if instructions.len() > 1 {
+        println!(
+            "{} {}",
+            style("Created recipient token account:").green().bold(),
+            style(destination).cyan()
+        );
+    }
+    println!(
+        "{} {}",
+        style("Transferred:").green().bold(),
+        style(format!(
+            "{} tokens to {destination}",
+            format_token_amount(amount, decimals)
+        ))
+        .cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_close_token_account(
+    ctx: &ScillaContext,
+    token_account: &Pubkey,
+) -> anyhow::Result<()> {
+    let account = ctx
+        .rpc()
+        .get_account(token_account)
+        .await
+        .map_err(|_| anyhow!("Token account {token_account} does not exist"))?;
+    let program_id = token_program_of(&account)
+        .ok_or_else(|| anyhow!("{token_account} is not a token account"))?;
+    let info = unpack_token_account(&account.data)?;
+
+    if info.account.owner != *ctx.pubkey() {
+        bail!(
+            "{token_account} is owned by {}, not the configured wallet",
+            info.account.owner
+        );
+    }
+    // Wrapped SOL accounts hand their balance back as lamports on close
+    if !info.account.is_native() && info.account.amount != 0 {
+        bail!(
+            "Token account {token_account} still holds {} raw tokens; transfer or burn them first",
+            info.account.amount
+        );
+    }
+
+    let close_ix = close_account(&program_id, token_account, ctx.pubkey(), ctx.pubkey(), &[])?;
+    let signature = build_and_send_tx(ctx, &[close_ix], &[ctx.keypair()]).await?;
+
+    println!(
+        "{} {}",
+        style("Token account closed:").green().bold(),
+        style(token_account).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Reclaimed:").green().bold(),
+        style(format!("{} SOL", lamports_to_sol(account.lamports))).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
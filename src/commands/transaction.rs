@@ -1,20 +1,57 @@
 use {
     crate::{
-        commands::CommandExec,
+        commands::{
+            CommandExec,
+            stake::{fetch_new_rate_activation_epoch, fetch_stake_history},
+        },
+        config::expand_tilde,
+        constants::{DEFAULT_SIGNATURE_PAGE_SIZE, LAMPORTS_PER_SOL, MAX_SIGNATURE_PAGE_SIZE},
         context::ScillaContext,
         error::ScillaResult,
-        misc::helpers::{bincode_deserialize, decode_base58, decode_base64},
-        prompt::prompt_data,
-        ui::show_spinner,
+        misc::{
+            batch::{
+                BatchOperation, PLAN_COLUMNS, PlanRecord, RESULT_COLUMNS, STATUS_FAILED,
+                STATUS_SUCCESS, STATUS_UNKNOWN, accounts_to_fetch, build_instruction,
+                fetch_accounts, load_plan, pack_instructions, parse_operation,
+            },
+            confirmation::{TrackOptions, TrackOutcome, track_confirmation},
+            helpers::{
+                bincode_deserialize, build_tx, decode_base58, decode_base64, lamports_to_sol,
+                write_csv,
+            },
+            instructions::{
+                DecodedInstruction, advance_nonce_account, decode_ui_instruction,
+                decode_unresolved_instruction,
+            },
+        },
+        prompt::{prompt_data, prompt_optional_data},
+        ui::{new_spinner, show_spinner},
     },
     comfy_table::{Cell, Table, presets::UTF8_FULL},
     console::style,
-    inquire::Select,
-    solana_rpc_client_api::config::RpcTransactionConfig,
+    indicatif::{ProgressBar, ProgressStyle},
+    inquire::{Confirm, Select},
+    solana_commitment_config::CommitmentConfig,
+    solana_instruction::Instruction,
+    solana_message::VersionedMessage,
+    solana_pubkey::Pubkey,
+    solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config,
+    solana_rpc_client_api::{
+        client_error::ErrorKind as ClientErrorKind, config::RpcTransactionConfig,
+        request::RpcError, response::RpcConfirmedTransactionStatusWithSignature,
+    },
     solana_signature::Signature,
     solana_transaction::versioned::VersionedTransaction,
-    solana_transaction_status::{EncodedTransaction, UiMessage, UiTransactionEncoding},
-    std::fmt,
+    solana_transaction_status::{
+        EncodedTransaction, TransactionConfirmationStatus, UiInstruction, UiMessage,
+        UiTransactionEncoding, UiTransactionStatusMeta, option_serializer::OptionSerializer,
+    },
+    std::{
+        collections::BTreeMap,
+        fmt, fs,
+        path::{Path, PathBuf},
+        time::Duration,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -22,7 +59,10 @@ pub enum TransactionCommand {
     CheckConfirmation,
     FetchStatus,
     FetchTransaction,
+    AddressHistory,
+    DecodeTransaction,
     SendTransaction,
+    RunBatchPlan,
     GoBack,
 }
 
@@ -32,7 +72,10 @@ impl TransactionCommand {
             Self::CheckConfirmation => "Checking transaction confirmation…",
             Self::FetchStatus => "Fetching transaction status…",
             Self::FetchTransaction => "Fetching full transaction data…",
+            Self::AddressHistory => "Fetching signatures for address…",
+            Self::DecodeTransaction => "Decoding transaction…",
             Self::SendTransaction => "Sending transaction…",
+            Self::RunBatchPlan => "Validating plan against on-chain state…",
             Self::GoBack => "Going back…",
         }
     }
@@ -44,7 +87,10 @@ impl fmt::Display for TransactionCommand {
             Self::CheckConfirmation => "Check Transaction Confirmation",
             Self::FetchStatus => "Fetch Transaction Status",
             Self::FetchTransaction => "Fetch Transaction",
+            Self::AddressHistory => "Address History",
+            Self::DecodeTransaction => "Decode Transaction (offline)",
             Self::SendTransaction => "Send Transaction",
+            Self::RunBatchPlan => "Run Batch Plan",
             Self::GoBack => "Go back",
         })
     }
@@ -77,6 +123,53 @@ impl TransactionCommand {
                 )
                 .await?;
             }
+            TransactionCommand::AddressHistory => {
+                let address: Pubkey = prompt_data("Enter address:")?;
+                let before: Option<Signature> = prompt_optional_data(
+                    "Start before signature (leave empty for the most recent):",
+                )?;
+                let until: Option<Signature> =
+                    prompt_optional_data("Stop at signature (leave empty for no limit):")?;
+                let limit: usize = prompt_optional_data(&format!(
+                    "Page size (1-{MAX_SIGNATURE_PAGE_SIZE}, default \
+                     {DEFAULT_SIGNATURE_PAGE_SIZE}):"
+                ))?
+                .unwrap_or(DEFAULT_SIGNATURE_PAGE_SIZE)
+                .clamp(1, MAX_SIGNATURE_PAGE_SIZE);
+                let errors_only = Confirm::new("Show failed transactions only?")
+                    .with_default(false)
+                    .prompt()?;
+
+                process_address_history(
+                    ctx,
+                    &address,
+                    before,
+                    until,
+                    limit,
+                    errors_only,
+                    self.spinner_msg(),
+                )
+                .await?;
+            }
+            TransactionCommand::DecodeTransaction => {
+                let encoding = Select::new(
+                    "Select encoding format:",
+                    vec![UiTransactionEncoding::Base64, UiTransactionEncoding::Base58],
+                )
+                .prompt()?;
+
+                let from_file = Select::new("Read transaction from:", vec!["Input", "File"])
+                    .prompt()?
+                    == "File";
+                let encoded_tx = if from_file {
+                    let path: String = prompt_data("Enter file path:")?;
+                    fs::read_to_string(expand_tilde(&path))?
+                } else {
+                    prompt_data("Enter encoded transaction:")?
+                };
+
+                process_decode_transaction(encoding, &encoded_tx)?;
+            }
             TransactionCommand::SendTransaction => {
                 println!(
                     "{}",
@@ -92,12 +185,39 @@ impl TransactionCommand {
                 .prompt()?;
 
                 let encoded_tx: String = prompt_data("Enter encoded transaction:")?;
+                let resend_secs: Option<u64> = prompt_optional_data(
+                    "Re-send every N seconds until it lands (leave empty to send once):",
+                )?;
 
-                show_spinner(
+                let tx = show_spinner(
                     self.spinner_msg(),
                     process_send_transaction(ctx, encoding, &encoded_tx),
                 )
                 .await?;
+
+                process_track_confirmation(
+                    ctx,
+                    &tx,
+                    resend_secs
+                        .filter(|secs| *secs > 0)
+                        .map(Duration::from_secs),
+                )
+                .await?;
+            }
+            TransactionCommand::RunBatchPlan => {
+                let path: String = prompt_data("Enter plan file path (.csv or .toml):")?;
+                let plan_path = expand_tilde(&path);
+
+                let plan =
+                    show_spinner(self.spinner_msg(), prepare_batch_plan(ctx, &plan_path)).await?;
+
+                if print_batch_plan(ctx, &plan)
+                    && Confirm::new("Execute this plan?")
+                        .with_default(false)
+                        .prompt()?
+                {
+                    execute_batch_plan(ctx, &plan_path, &plan).await?;
+                }
             }
             TransactionCommand::GoBack => return Ok(CommandExec::GoBack),
         }
@@ -202,6 +322,9 @@ async fn process_fetch_transaction(
 
     if let Some(meta) = &tx.transaction.meta {
         table.add_row(vec![Cell::new("Fee (lamports)"), Cell::new(meta.fee)]);
+        if let OptionSerializer::Some(units) = meta.compute_units_consumed {
+            table.add_row(vec![Cell::new("Compute Units"), Cell::new(units)]);
+        }
         table.add_row(vec![
             Cell::new("Status"),
             Cell::new(if meta.err.is_none() {
@@ -218,8 +341,9 @@ async fn process_fetch_transaction(
     let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
         anyhow::bail!("Transaction encoding is not JSON");
     };
+    let meta = tx.transaction.meta.as_ref();
 
-    match &ui_tx.message {
+    let (account_keys, instructions): (Vec<String>, Vec<UiInstruction>) = match &ui_tx.message {
         UiMessage::Parsed(parsed_msg) => {
             println!("\n{}", style("TRANSACTION MESSAGE").cyan().bold());
 
@@ -261,6 +385,15 @@ async fn process_fetch_transaction(
                 }
                 println!("{}", accounts_table);
             }
+
+            (
+                parsed_msg
+                    .account_keys
+                    .iter()
+                    .map(|account| account.pubkey.clone())
+                    .collect(),
+                parsed_msg.instructions.clone(),
+            )
         }
         UiMessage::Raw(raw_msg) => {
             println!("\n{}", style("TRANSACTION MESSAGE (Raw)").cyan().bold());
@@ -289,14 +422,378 @@ async fn process_fetch_transaction(
                     println!("  {}. {}", idx, key);
                 }
             }
+
+            // Lookup table addresses follow the static keys, writable first
+            let mut keys = raw_msg.account_keys.clone();
+            if let Some(OptionSerializer::Some(loaded)) = meta.map(|meta| &meta.loaded_addresses) {
+                keys.extend(loaded.writable.iter().cloned());
+                keys.extend(loaded.readonly.iter().cloned());
+            }
+
+            (
+                keys,
+                raw_msg
+                    .instructions
+                    .iter()
+                    .cloned()
+                    .map(UiInstruction::Compiled)
+                    .collect(),
+            )
+        }
+    };
+
+    print_instructions(&instructions, meta, &account_keys)?;
+
+    if let Some(meta) = meta {
+        print_balance_changes(meta, &account_keys);
+
+        if let OptionSerializer::Some(logs) = &meta.log_messages
+            && !logs.is_empty()
+        {
+            println!("\n{}", style("LOG MESSAGES").cyan().bold());
+            for log in logs {
+                println!("  {}", style(log).dim());
+            }
         }
     }
 
     Ok(())
 }
 
-async fn process_send_transaction(
+fn print_instructions(
+    instructions: &[UiInstruction],
+    meta: Option<&UiTransactionStatusMeta>,
+    account_keys: &[String],
+) -> anyhow::Result<()> {
+    if instructions.is_empty() {
+        return Ok(());
+    }
+
+    let inner_instructions = meta.and_then(|meta| match &meta.inner_instructions {
+        OptionSerializer::Some(inner) => Some(inner),
+        _ => None,
+    });
+
+    println!("\n{}", style("INSTRUCTIONS").cyan().bold());
+
+    for (idx, instruction) in instructions.iter().enumerate() {
+        let label = format!("#{}", idx + 1);
+        print_instruction(&label, &decode_ui_instruction(instruction, account_keys)?);
+
+        let inner = inner_instructions
+            .into_iter()
+            .flatten()
+            .filter(|inner| inner.index as usize == idx)
+            .flat_map(|inner| &inner.instructions);
+
+        for (inner_idx, inner_instruction) in inner.enumerate() {
+            let label = format!("  #{}.{}", idx + 1, inner_idx + 1);
+            print_instruction(
+                &label,
+                &decode_ui_instruction(inner_instruction, account_keys)?,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn print_instruction(label: &str, instruction: &DecodedInstruction) {
+    println!(
+        "\n{} {} {}",
+        style(label).bold(),
+        style(&instruction.program).cyan(),
+        style(format!("› {}", instruction.name)).bold()
+    );
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (key, value) in &instruction.args {
+        table.add_row(vec![Cell::new(key), Cell::new(value)]);
+    }
+    if !instruction.accounts.is_empty() {
+        table.add_row(vec![
+            Cell::new("Accounts"),
+            Cell::new(instruction.accounts.join("\n")),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+fn print_balance_changes(meta: &UiTransactionStatusMeta, account_keys: &[String]) {
+    let changes: Vec<_> = meta
+        .pre_balances
+        .iter()
+        .zip(&meta.post_balances)
+        .enumerate()
+        .filter(|(_, (pre, post))| pre != post)
+        .collect();
+
+    if changes.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{} {}",
+        style("BALANCE CHANGES").cyan().bold(),
+        style("(unchanged accounts hidden)").dim()
+    );
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Pre (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Post (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Change (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, (pre, post)) in changes {
+        let delta = *post as i128 - *pre as i128;
+        let delta_sol = delta as f64 / LAMPORTS_PER_SOL as f64;
+
+        table.add_row(vec![
+            Cell::new(
+                account_keys
+                    .get(idx)
+                    .map(String::as_str)
+                    .unwrap_or("<unknown>"),
+            ),
+            Cell::new(lamports_to_sol(*pre)),
+            Cell::new(lamports_to_sol(*post)),
+            Cell::new(if delta < 0 {
+                style(format!("{delta_sol:.9}")).red()
+            } else {
+                style(format!("+{delta_sol:.9}")).green()
+            }),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+/// Lets the user open any transaction from a listing in detail
+pub async fn prompt_inspect_transaction(
     ctx: &ScillaContext,
+    signatures: &[Signature],
+) -> anyhow::Result<()> {
+    const DONE: &str = "Done";
+
+    if signatures.is_empty() {
+        return Ok(());
+    }
+
+    let mut options = vec![DONE.to_string()];
+    options.extend(
+        signatures
+            .iter()
+            .enumerate()
+            .map(|(idx, signature)| format!("{}. {signature}", idx + 1)),
+    );
+
+    loop {
+        let choice = Select::new("Inspect a transaction:", options.clone()).prompt()?;
+        if choice == DONE {
+            return Ok(());
+        }
+
+        let Some(signature) = choice
+            .split_once(". ")
+            .and_then(|(_, signature)| signature.parse::<Signature>().ok())
+        else {
+            continue;
+        };
+
+        show_spinner(
+            TransactionCommand::FetchTransaction.spinner_msg(),
+            process_fetch_transaction(ctx, &signature),
+        )
+        .await?;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HistoryAction {
+    NextPage,
+    PreviousPage,
+    OpenTransaction,
+    Done,
+}
+
+impl fmt::Display for HistoryAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HistoryAction::NextPage => "Next page",
+            HistoryAction::PreviousPage => "Previous page",
+            HistoryAction::OpenTransaction => "Open a transaction",
+            HistoryAction::Done => "Done",
+        })
+    }
+}
+
+async fn process_address_history(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+    errors_only: bool,
+    spinner_msg: &str,
+) -> anyhow::Result<()> {
+    // Cursors of the pages already visited, so we can step back
+    let mut previous_cursors: Vec<Option<Signature>> = Vec::new();
+    let mut cursor = before;
+    let mut page_number = 1;
+
+    loop {
+        let page = show_spinner(
+            spinner_msg,
+            fetch_signatures_page(ctx, address, cursor, until, limit),
+        )
+        .await?;
+
+        let shown: Vec<_> = page
+            .iter()
+            .filter(|entry| !errors_only || entry.err.is_some())
+            .collect();
+
+        print_signatures_page(address, page_number, &shown, page.len());
+
+        let last_signature = page
+            .last()
+            .and_then(|entry| entry.signature.parse::<Signature>().ok());
+        let has_next = page.len() == limit && last_signature.is_some();
+
+        let mut actions = Vec::new();
+        if has_next {
+            actions.push(HistoryAction::NextPage);
+        }
+        if !previous_cursors.is_empty() {
+            actions.push(HistoryAction::PreviousPage);
+        }
+        if !shown.is_empty() {
+            actions.push(HistoryAction::OpenTransaction);
+        }
+        actions.push(HistoryAction::Done);
+
+        let signatures: Vec<Signature> = shown
+            .iter()
+            .filter_map(|entry| entry.signature.parse().ok())
+            .collect();
+
+        loop {
+            match Select::new("Navigate:", actions.clone()).prompt()? {
+                HistoryAction::NextPage => {
+                    previous_cursors.push(cursor);
+                    cursor = last_signature;
+                    page_number += 1;
+                    break;
+                }
+                HistoryAction::PreviousPage => {
+                    cursor = previous_cursors.pop().flatten();
+                    page_number -= 1;
+                    break;
+                }
+                HistoryAction::OpenTransaction => {
+                    prompt_inspect_transaction(ctx, &signatures).await?;
+                }
+                HistoryAction::Done => return Ok(()),
+            }
+        }
+    }
+}
+
+async fn fetch_signatures_page(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    // Signature history is not served at processed commitment
+    let commitment = if ctx.rpc().commitment().is_at_least_confirmed() {
+        ctx.rpc().commitment()
+    } else {
+        CommitmentConfig::confirmed()
+    };
+
+    let page = ctx
+        .rpc()
+        .get_signatures_for_address_with_config(
+            address,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(limit),
+                commitment: Some(commitment),
+            },
+        )
+        .await?;
+
+    Ok(page)
+}
+
+fn print_signatures_page(
+    address: &Pubkey,
+    page_number: usize,
+    entries: &[&RpcConfirmedTransactionStatusWithSignature],
+    fetched: usize,
+) {
+    println!(
+        "\n{} {}",
+        style(format!("TRANSACTION HISTORY — {address}"))
+            .green()
+            .bold(),
+        style(format!(
+            "(page {page_number}, {} of {fetched} shown)",
+            entries.len()
+        ))
+        .dim()
+    );
+
+    if entries.is_empty() {
+        println!("{}", style("No transactions on this page").yellow());
+        return;
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Signature").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Slot").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Block Time").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Memo").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, entry) in entries.iter().enumerate() {
+        let block_time = entry
+            .block_time
+            .and_then(chrono::DateTime::<chrono::Utc>::from_timestamp_secs)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| "~".to_string());
+
+        table.add_row(vec![
+            Cell::new(idx + 1),
+            Cell::new(&entry.signature),
+            Cell::new(entry.slot),
+            Cell::new(block_time),
+            Cell::new(match &entry.err {
+                None => style("Success".to_string()).green(),
+                Some(err) => style(format!("Error: {err:?}")).red(),
+            }),
+            Cell::new(entry.memo.as_deref().unwrap_or("")),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+fn process_decode_transaction(
     encoding: UiTransactionEncoding,
     encoded_tx: &str,
 ) -> anyhow::Result<()> {
@@ -306,6 +803,560 @@ async fn process_send_transaction(
         _ => unreachable!("The available encoding options are Base64 and Base58"),
     };
 
+    let tx: VersionedTransaction =
+        bincode_deserialize(&tx_bytes, "encoded transaction to VersionedTransaction")?;
+    let message = &tx.message;
+    let header = message.header();
+    let static_keys = message.static_account_keys();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Version"),
+            Cell::new(match message {
+                VersionedMessage::Legacy(_) => "legacy",
+                VersionedMessage::V0(_) => "v0",
+            }),
+        ])
+        .add_row(vec![
+            Cell::new("Required Signatures"),
+            Cell::new(header.num_required_signatures),
+        ])
+        .add_row(vec![
+            Cell::new("Readonly Signed Accounts"),
+            Cell::new(header.num_readonly_signed_accounts),
+        ])
+        .add_row(vec![
+            Cell::new("Readonly Unsigned Accounts"),
+            Cell::new(header.num_readonly_unsigned_accounts),
+        ])
+        .add_row(vec![
+            Cell::new("Static Account Keys"),
+            Cell::new(static_keys.len()),
+        ]);
+
+    match advance_nonce_account(message) {
+        Some(nonce_account) => {
+            table
+                .add_row(vec![Cell::new("Nonce Account"), Cell::new(nonce_account)])
+                .add_row(vec![
+                    Cell::new("Nonce Value"),
+                    Cell::new(message.recent_blockhash()),
+                ]);
+        }
+        None => {
+            table.add_row(vec![
+                Cell::new("Recent Blockhash"),
+                Cell::new(message.recent_blockhash()),
+            ]);
+        }
+    }
+
+    println!("\n{}", style("DECODED TRANSACTION").green().bold());
+    println!("{table}");
+
+    let message_bytes = message.serialize();
+    let mut signatures_table = Table::new();
+    signatures_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Signer").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Signature").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for idx in 0..header.num_required_signatures as usize {
+        let signer = static_keys
+            .get(idx)
+            .map(|key| key.to_string())
+            .unwrap_or_else(|| "<missing key>".to_string());
+        let signature = tx.signatures.get(idx);
+
+        let status = match signature {
+            None => style("Missing").yellow(),
+            Some(signature) if *signature == Signature::default() => style("Missing").yellow(),
+            Some(signature) => match static_keys.get(idx) {
+                Some(key) if signature.verify(key.as_ref(), &message_bytes) => {
+                    style("Valid").green()
+                }
+                _ => style("Invalid").red(),
+            },
+        };
+
+        signatures_table.add_row(vec![
+            Cell::new(idx),
+            Cell::new(signer),
+            Cell::new(
+                signature
+                    .map(|signature| signature.to_string())
+                    .unwrap_or_default(),
+            ),
+            Cell::new(status),
+        ]);
+    }
+
+    println!("\n{}", style("SIGNATURES").cyan().bold());
+    println!("{signatures_table}");
+
+    let mut accounts_table = Table::new();
+    accounts_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Index").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Pubkey").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Signer").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Writable").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, key) in static_keys.iter().enumerate() {
+        accounts_table.add_row(vec![
+            Cell::new(idx),
+            Cell::new(key),
+            Cell::new(if message.is_signer(idx) { "✓" } else { "" }),
+            Cell::new(if message.is_maybe_writable(idx, None) {
+                "✓"
+            } else {
+                ""
+            }),
+        ]);
+    }
+
+    println!("\n{}", style("ACCOUNT KEYS").cyan().bold());
+    println!("{accounts_table}");
+
+    if let Some(lookups) = message.address_table_lookups()
+        && !lookups.is_empty()
+    {
+        let mut lookups_table = Table::new();
+        lookups_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Lookup Table").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Writable Indexes").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Readonly Indexes").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for lookup in lookups {
+            lookups_table.add_row(vec![
+                Cell::new(lookup.account_key),
+                Cell::new(format!("{:?}", lookup.writable_indexes)),
+                Cell::new(format!("{:?}", lookup.readonly_indexes)),
+            ]);
+        }
+
+        println!("\n{}", style("ADDRESS TABLE LOOKUPS").cyan().bold());
+        println!("{lookups_table}");
+        println!(
+            "{}",
+            style("Accounts loaded from lookup tables are shown as <lookup N> below").dim()
+        );
+    }
+
+    println!("\n{}", style("INSTRUCTIONS").cyan().bold());
+    for (idx, instruction) in message.instructions().iter().enumerate() {
+        print_instruction(
+            &format!("#{}", idx + 1),
+            &decode_unresolved_instruction(instruction, static_keys),
+        );
+    }
+
+    Ok(())
+}
+
+/// A validated plan: every row with the instruction it will send, or the
+/// reason it can't be sent
+struct BatchPlan {
+    records: Vec<PlanRecord>,
+    steps: Vec<BatchStep>,
+}
+
+enum BatchStep {
+    /// Recorded as successful by a previous run
+    Done,
+    Ready(BatchOperation, Instruction),
+    Invalid(String),
+}
+
+async fn prepare_batch_plan(ctx: &ScillaContext, plan_path: &Path) -> anyhow::Result<BatchPlan> {
+    let mut records = load_plan(plan_path)?;
+    let unresolved = resolve_unknown_outcomes(ctx, &mut records).await?;
+
+    let parsed: Vec<Option<anyhow::Result<BatchOperation>>> = records
+        .iter()
+        .enumerate()
+        .map(|(idx, record)| match unresolved.get(&idx) {
+            Some(reason) => Some(Err(anyhow::Error::msg(reason.clone()))),
+            None => (!record.already_succeeded()).then(|| parse_operation(record)),
+        })
+        .collect();
+
+    let operations: Vec<&BatchOperation> = parsed
+        .iter()
+        .filter_map(|operation| operation.as_ref()?.as_ref().ok())
+        .collect();
+
+    let to_fetch = accounts_to_fetch(&operations);
+    let (accounts, epoch_info, stake_history, new_rate_activation_epoch) = tokio::try_join!(
+        fetch_accounts(ctx, &to_fetch),
+        async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        fetch_stake_history(ctx),
+        async {
+            let epoch_schedule = ctx.rpc().get_epoch_schedule().await?;
+            fetch_new_rate_activation_epoch(ctx, &epoch_schedule).await
+        },
+    )?;
+
+    let steps = parsed
+        .into_iter()
+        .map(|operation| match operation {
+            None => BatchStep::Done,
+            Some(Err(err)) => BatchStep::Invalid(err.to_string()),
+            Some(Ok(operation)) => {
+                match build_instruction(
+                    &operation,
+                    ctx.pubkey(),
+                    &accounts,
+                    epoch_info.epoch,
+                    &stake_history,
+                    new_rate_activation_epoch,
+                ) {
+                    Ok(instruction) => BatchStep::Ready(operation, instruction),
+                    Err(err) => BatchStep::Invalid(err.to_string()),
+                }
+            }
+        })
+        .collect();
+
+    Ok(BatchPlan { records, steps })
+}
+
+/// Looks up rows a previous run left as `unknown` and records whether their
+/// transaction landed. Returns the rows that still can't be decided, keyed by
+/// position, with the reason; those must not be sent again blindly.
+async fn resolve_unknown_outcomes(
+    ctx: &ScillaContext,
+    records: &mut [PlanRecord],
+) -> anyhow::Result<BTreeMap<usize, String>> {
+    let mut unresolved = BTreeMap::new();
+    let mut unknown: Vec<(usize, Signature)> = Vec::new();
+    for (idx, record) in records.iter().enumerate() {
+        match record.unknown_signature() {
+            Ok(Some(signature)) => unknown.push((idx, signature)),
+            Ok(None) => {}
+            Err(err) => {
+                unresolved.insert(idx, err.to_string());
+            }
+        }
+    }
+
+    let signatures: Vec<Signature> = unknown.iter().map(|(_, signature)| *signature).collect();
+    let mut statuses = Vec::with_capacity(signatures.len());
+    for chunk in signatures.chunks(256) {
+        statuses.extend(
+            ctx.rpc()
+                .get_signature_statuses_with_history(chunk)
+                .await?
+                .value,
+        );
+    }
+
+    for ((idx, signature), status) in unknown.into_iter().zip(statuses) {
+        match status {
+            Some(status) => match status.err {
+                None => records[idx].set_result(STATUS_SUCCESS, Some(&signature), ""),
+                Some(err) => {
+                    records[idx].set_result(STATUS_FAILED, Some(&signature), &err.to_string())
+                }
+            },
+            None => {
+                unresolved.insert(
+                    idx,
+                    format!(
+                        "outcome of {signature} is unknown; check it did not land, then set the \
+                         row's status to `{STATUS_FAILED}` to retry"
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(unresolved)
+}
+
+/// Prints the plan and returns whether it can be executed
+fn print_batch_plan(ctx: &ScillaContext, plan: &BatchPlan) -> bool {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Row").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Operation").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Check").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (record, step) in plan.records.iter().zip(&plan.steps) {
+        let (operation, check) = match step {
+            BatchStep::Done => (
+                record.get("kind").unwrap_or("-").to_string(),
+                style("Already done".to_string()).dim(),
+            ),
+            BatchStep::Ready(operation, _) => {
+                (operation.to_string(), style("OK".to_string()).green())
+            }
+            BatchStep::Invalid(err) => (
+                record.get("kind").unwrap_or("-").to_string(),
+                style(err.clone()).red(),
+            ),
+        };
+        table.add_row(vec![
+            Cell::new(record.row),
+            Cell::new(operation),
+            Cell::new(check),
+        ]);
+    }
+
+    println!("\n{}", style("BATCH PLAN").green().bold());
+    println!("{table}");
+
+    let invalid = plan
+        .steps
+        .iter()
+        .filter(|step| matches!(step, BatchStep::Invalid(_)))
+        .count();
+    if invalid > 0 {
+        println!(
+            "{}",
+            style(format!(
+                "{invalid} row(s) failed validation; fix the plan and run it again. Nothing was \
+                 sent."
+            ))
+            .red()
+            .bold()
+        );
+        return false;
+    }
+
+    let instructions = ready_instructions(plan);
+    if instructions.is_empty() {
+        println!("{}", style("Every row has already been executed").yellow());
+        return false;
+    }
+
+    println!(
+        "{}",
+        style(format!(
+            "{} operation(s) will be sent in {} transaction(s), signed by {}",
+            instructions.len(),
+            pack_instructions(ctx.pubkey(), &instructions).len(),
+            ctx.pubkey()
+        ))
+        .cyan()
+    );
+
+    true
+}
+
+fn ready_instructions(plan: &BatchPlan) -> Vec<Instruction> {
+    plan.steps
+        .iter()
+        .filter_map(|step| match step {
+            BatchStep::Ready(_, instruction) => Some(instruction.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn execute_batch_plan(
+    ctx: &ScillaContext,
+    plan_path: &Path,
+    plan: &BatchPlan,
+) -> anyhow::Result<()> {
+    // Positions in `plan.records` of the rows being sent, aligned with the
+    // instructions handed to the packer
+    let pending: Vec<usize> = plan
+        .steps
+        .iter()
+        .enumerate()
+        .filter(|(_, step)| matches!(step, BatchStep::Ready(..)))
+        .map(|(idx, _)| idx)
+        .collect();
+    let instructions = ready_instructions(plan);
+    let batches = pack_instructions(ctx.pubkey(), &instructions);
+
+    let progress = ProgressBar::new(instructions.len() as u64);
+    progress.set_style(
+        ProgressStyle::with_template("{bar:40.cyan/blue} {pos}/{len} operations {msg}")?
+            .progress_chars("█▓░"),
+    );
+
+    // The results file is rewritten after every step, and a row is marked
+    // `unknown` with its signature before it is sent, so an interrupted run
+    // leaves a record of everything that may have landed
+    let results_path = batch_results_path(plan_path);
+    let mut records = plan.records.clone();
+    for batch in &batches {
+        let batch_instructions: Vec<Instruction> =
+            batch.iter().map(|idx| instructions[*idx].clone()).collect();
+        let rows: Vec<usize> = batch.iter().map(|idx| pending[*idx]).collect();
+        progress.set_message(format!("(sending {} in one transaction)", batch.len()));
+
+        let mut set_result = |status: &str, signature: Option<&Signature>, error: &str| {
+            for row in &rows {
+                records[*row].set_result(status, signature, error);
+            }
+            write_batch_results(&results_path, &records)
+        };
+
+        let tx = match build_tx(ctx, &batch_instructions, &[ctx.keypair()]).await {
+            Ok(tx) => tx,
+            Err(err) => {
+                set_result(STATUS_FAILED, None, &err.to_string())?;
+                progress.inc(batch.len() as u64);
+                continue;
+            }
+        };
+        let signature = tx.signatures[0];
+        set_result(STATUS_UNKNOWN, Some(&signature), "sending")?;
+
+        let (status, error) = match ctx.rpc().send_transaction(&tx).await {
+            // The node answered with an error (e.g. a failed preflight), so
+            // the transaction was not forwarded
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    ClientErrorKind::RpcError(RpcError::RpcResponseError { .. })
+                ) =>
+            {
+                (STATUS_FAILED, err.to_string())
+            }
+            Err(err) => (STATUS_UNKNOWN, err.to_string()),
+            Ok(_) => match track_confirmation(
+                ctx,
+                &tx,
+                &TrackOptions::for_commitment(ctx.rpc().commitment()),
+                |_| {},
+            )
+            .await
+            {
+                Ok(report) => match report.outcome {
+                    TrackOutcome::Landed => (STATUS_SUCCESS, String::new()),
+                    TrackOutcome::Failed(err) => (STATUS_FAILED, err.to_string()),
+                    TrackOutcome::Expired => (
+                        STATUS_FAILED,
+                        "blockhash expired before it landed".to_string(),
+                    ),
+                },
+                Err(err) => (STATUS_UNKNOWN, err.to_string()),
+            },
+        };
+        set_result(status, Some(&signature), &error)?;
+        progress.inc(batch.len() as u64);
+    }
+    progress.finish_with_message("done");
+
+    let count = |status: &str| {
+        pending
+            .iter()
+            .filter(|idx| records[**idx].get("status") == Some(status))
+            .count()
+    };
+    let (succeeded, failed, unknown) = (
+        count(STATUS_SUCCESS),
+        count(STATUS_FAILED),
+        count(STATUS_UNKNOWN),
+    );
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Transactions Sent"),
+            Cell::new(batches.len()),
+        ])
+        .add_row(vec![
+            Cell::new("Succeeded"),
+            Cell::new(style(succeeded).green()),
+        ])
+        .add_row(vec![Cell::new("Failed"), Cell::new(style(failed).red())])
+        .add_row(vec![
+            Cell::new("Unknown"),
+            Cell::new(style(unknown).yellow()),
+        ])
+        .add_row(vec![
+            Cell::new("Results File"),
+            Cell::new(results_path.display()),
+        ]);
+
+    println!("\n{}", style("BATCH RESULTS").green().bold());
+    println!("{table}");
+
+    if failed > 0 || unknown > 0 {
+        println!(
+            "{}",
+            style(
+                "Run the results file as a plan to retry only the failed rows; unknown rows are \
+                 checked on chain first"
+            )
+            .yellow()
+        );
+    }
+
+    Ok(())
+}
+
+fn write_batch_results(path: &Path, records: &[PlanRecord]) -> anyhow::Result<()> {
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            PLAN_COLUMNS
+                .iter()
+                .chain(&RESULT_COLUMNS)
+                .map(|column| record.get(column).unwrap_or_default().to_string())
+                .collect()
+        })
+        .collect();
+    let headers: Vec<&str> = PLAN_COLUMNS
+        .iter()
+        .chain(&RESULT_COLUMNS)
+        .copied()
+        .collect();
+    write_csv(path, &headers, &rows)
+}
+
+/// `plan.csv` → `plan.results.csv`; a results file is updated in place
+fn batch_results_path(plan_path: &Path) -> PathBuf {
+    let stem = plan_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "plan".to_string());
+
+    let file_name = if stem.ends_with(".results") {
+        format!("{stem}.csv")
+    } else {
+        format!("{stem}.results.csv")
+    };
+
+    plan_path.with_file_name(file_name)
+}
+
+async fn process_send_transaction(
+    ctx: &ScillaContext,
+    encoding: UiTransactionEncoding,
+    encoded_tx: &str,
+) -> anyhow::Result<VersionedTransaction> {
+    let tx_bytes = match encoding {
+        UiTransactionEncoding::Base64 => decode_base64(encoded_tx)?,
+        UiTransactionEncoding::Base58 => decode_base58(encoded_tx)?,
+        _ => unreachable!("The available encoding options are Base64 and Base58"),
+    };
+
     let tx: VersionedTransaction =
         bincode_deserialize(&tx_bytes, "encoded transaction to VersionedTransaction")?;
 
@@ -317,5 +1368,92 @@ async fn process_send_transaction(
         style(signature).cyan()
     );
 
+    Ok(tx)
+}
+
+/// Follows a sent transaction through processed, confirmed and finalized
+async fn process_track_confirmation(
+    ctx: &ScillaContext,
+    tx: &VersionedTransaction,
+    resend_interval: Option<Duration>,
+) -> anyhow::Result<()> {
+    let options = TrackOptions {
+        target: TransactionConfirmationStatus::Finalized,
+        resend_interval,
+    };
+
+    let spinner = new_spinner("Waiting for the transaction to be processed…");
+    let report = track_confirmation(ctx, tx, &options, |stage| {
+        spinner.println(format!(
+            "  {} {} at slot {} {}",
+            style("✓").green(),
+            confirmation_stage_name(&stage.status),
+            stage.slot,
+            style(format!("(+{:.2}s)", stage.elapsed.as_secs_f64())).dim()
+        ));
+        if stage.status != TransactionConfirmationStatus::Finalized {
+            spinner.set_message(format!(
+                "{} at slot {}, waiting for finalization…",
+                confirmation_stage_name(&stage.status),
+                stage.slot
+            ));
+        }
+    })
+    .await;
+    spinner.finish_and_clear();
+    let report = report?;
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Stage").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Slot").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Elapsed").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for stage in &report.stages {
+        table.add_row(vec![
+            Cell::new(confirmation_stage_name(&stage.status)),
+            Cell::new(stage.slot),
+            Cell::new(format!("{:.2}s", stage.elapsed.as_secs_f64())),
+        ]);
+    }
+
+    println!("\n{}", style("TRANSACTION CONFIRMATION").green().bold());
+    if !report.stages.is_empty() {
+        println!("{table}");
+    }
+
+    let outcome = match &report.outcome {
+        TrackOutcome::Landed => style(format!(
+            "Finalized after {:.2}s",
+            report.elapsed.as_secs_f64()
+        ))
+        .green()
+        .bold(),
+        TrackOutcome::Failed(err) => style(format!("Transaction failed: {err}")).red().bold(),
+        TrackOutcome::Expired => style(format!(
+            "Dropped: the blockhash expired after {:.2}s without the transaction landing. It can \
+             no longer be processed; rebuild it with a new blockhash.",
+            report.elapsed.as_secs_f64()
+        ))
+        .red()
+        .bold(),
+    };
+    println!("{outcome}");
+
+    if report.resends > 0 {
+        println!(
+            "{}",
+            style(format!("Re-sent {} time(s)", report.resends)).dim()
+        );
+    }
+
     Ok(())
 }
+
+fn confirmation_stage_name(status: &TransactionConfirmationStatus) -> &'static str {
+    match status {
+        TransactionConfirmationStatus::Processed => "Processed",
+        TransactionConfirmationStatus::Confirmed => "Confirmed",
+        TransactionConfirmationStatus::Finalized => "Finalized",
+    }
+}
@@ -2,24 +2,41 @@ use {
     crate::{
         ScillaContext, ScillaResult,
         commands::CommandExec,
-        misc::helpers::{
-            Commission, SolAmount, build_and_send_tx, fetch_account_with_epoch, lamports_to_sol,
-            read_keypair_from_path,
+        constants::DEFAULT_EPOCH_LIMIT,
+        misc::{
+            helpers::{
+                Commission, SolAmount, build_and_send_tx, fetch_account_with_epoch,
+                format_commission_bps, lamports_to_sol, read_keypair_from_path,
+            },
+            validator_info::{
+                ValidatorInfo, ValidatorInfoFields, fetch_all_validator_info,
+                validator_info_config_id,
+            },
         },
-        prompt::prompt_data,
+        prompt::{prompt_data, prompt_optional_data},
         ui::show_spinner,
     },
     anyhow::{anyhow, bail},
     comfy_table::{Cell, Table, presets::UTF8_FULL},
     console::style,
+    solana_account::Account,
+    solana_config_interface::{
+        instruction::{create_account_with_max_config_space, store},
+        state::ConfigKeys,
+    },
     solana_keypair::{Keypair, Signer},
     solana_pubkey::Pubkey,
-    solana_rpc_client_api::config::RpcGetVoteAccountsConfig,
+    solana_rpc_client_api::{
+        config::RpcGetVoteAccountsConfig, request::DELINQUENT_VALIDATOR_SLOT_DISTANCE,
+    },
     solana_vote_interface::{
-        instruction::{CreateVoteAccountConfig, authorize, create_account_with_config, withdraw},
-        state::{VoteAuthorize, VoteInit, VoteStateV4},
+        instruction::{
+            CreateVoteAccountConfig, authorize, authorize_checked, create_account_with_config,
+            update_commission, update_validator_identity, withdraw,
+        },
+        state::{VOTE_CREDITS_MAXIMUM_PER_SLOT, VoteAuthorize, VoteInit, VoteStateV4},
     },
-    std::{fmt, path::PathBuf},
+    std::{collections::BTreeMap, fmt, path::PathBuf},
 };
 
 /// Commands related to validator/vote account operations
@@ -27,8 +44,14 @@ use {
 pub enum VoteCommand {
     CreateVoteAccount,
     AuthorizeVoter,
+    AuthorizeWithdrawer,
+    UpdateCommission,
+    UpdateValidatorIdentity,
     WithdrawFromVoteAccount,
     ShowVoteAccount,
+    VoteAccountHealth,
+    PublishValidatorInfo,
+    SearchValidatorInfo,
     CloseVoteAccount,
     GoBack,
 }
@@ -38,8 +61,14 @@ impl VoteCommand {
         match self {
             VoteCommand::CreateVoteAccount => "Creating vote account…",
             VoteCommand::AuthorizeVoter => "Authorizing voter…",
+            VoteCommand::AuthorizeWithdrawer => "Authorizing withdrawer…",
+            VoteCommand::UpdateCommission => "Updating commission…",
+            VoteCommand::UpdateValidatorIdentity => "Updating validator identity…",
             VoteCommand::WithdrawFromVoteAccount => "Withdrawing SOL from vote account…",
             VoteCommand::ShowVoteAccount => "Fetching vote account details…",
+            VoteCommand::VoteAccountHealth => "Analyzing vote account health…",
+            VoteCommand::PublishValidatorInfo => "Publishing validator info…",
+            VoteCommand::SearchValidatorInfo => "Fetching published validator info…",
             VoteCommand::CloseVoteAccount => "Closing vote account…",
             VoteCommand::GoBack => "Going back…",
         }
@@ -51,8 +80,14 @@ impl fmt::Display for VoteCommand {
         let text = match self {
             VoteCommand::CreateVoteAccount => "Create vote account",
             VoteCommand::AuthorizeVoter => "Authorize voter",
+            VoteCommand::AuthorizeWithdrawer => "Authorize withdrawer",
+            VoteCommand::UpdateCommission => "Update commission",
+            VoteCommand::UpdateValidatorIdentity => "Update validator identity",
             VoteCommand::WithdrawFromVoteAccount => "Withdraw from vote account",
             VoteCommand::ShowVoteAccount => "Show vote account",
+            VoteCommand::VoteAccountHealth => "Vote account health",
+            VoteCommand::PublishValidatorInfo => "Publish validator info",
+            VoteCommand::SearchValidatorInfo => "Search validator info",
             VoteCommand::CloseVoteAccount => "Close vote account",
             VoteCommand::GoBack => "Go back",
         };
@@ -106,6 +141,67 @@ impl VoteCommand {
                 )
                 .await?;
             }
+            VoteCommand::AuthorizeWithdrawer => {
+                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                let withdraw_keypair_path: PathBuf =
+                    prompt_data("Enter Current Withdraw Authority Keypair Path:")?;
+                let new_withdraw_keypair_path: PathBuf =
+                    prompt_data("Enter New Withdraw Authority Keypair Path:")?;
+
+                let withdraw_keypair = read_keypair_from_path(&withdraw_keypair_path)?;
+                let new_withdraw_keypair = read_keypair_from_path(&new_withdraw_keypair_path)?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_authorize_withdrawer(
+                        ctx,
+                        &vote_account_pubkey,
+                        &withdraw_keypair,
+                        &new_withdraw_keypair,
+                    ),
+                )
+                .await?;
+            }
+            VoteCommand::UpdateCommission => {
+                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                let withdraw_keypair_path: PathBuf =
+                    prompt_data("Enter Withdraw Authority Keypair Path:")?;
+                let commission: Commission = prompt_data("Enter New Commission 0-100:")?;
+
+                let withdraw_keypair = read_keypair_from_path(&withdraw_keypair_path)?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_update_commission(
+                        ctx,
+                        &vote_account_pubkey,
+                        &withdraw_keypair,
+                        commission.value(),
+                    ),
+                )
+                .await?;
+            }
+            VoteCommand::UpdateValidatorIdentity => {
+                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                let withdraw_keypair_path: PathBuf =
+                    prompt_data("Enter Withdraw Authority Keypair Path:")?;
+                let identity_keypair_path: PathBuf =
+                    prompt_data("Enter New Identity Keypair Path:")?;
+
+                let withdraw_keypair = read_keypair_from_path(&withdraw_keypair_path)?;
+                let identity_keypair = read_keypair_from_path(&identity_keypair_path)?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_update_validator_identity(
+                        ctx,
+                        &vote_account_pubkey,
+                        &withdraw_keypair,
+                        &identity_keypair,
+                    ),
+                )
+                .await?;
+            }
             VoteCommand::WithdrawFromVoteAccount => {
                 let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
                 let authorized_keypair_path: PathBuf =
@@ -135,6 +231,52 @@ impl VoteCommand {
                 )
                 .await?;
             }
+            VoteCommand::VoteAccountHealth => {
+                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_vote_account_health(ctx, &vote_account_pubkey),
+                )
+                .await?;
+            }
+            VoteCommand::PublishValidatorInfo => {
+                let identity_keypair_path: PathBuf = prompt_data("Enter Identity Keypair Path:")?;
+                let name: String = prompt_data("Enter Validator Name:")?;
+                let website: Option<String> =
+                    prompt_optional_data("Enter Website (leave empty to skip):")?;
+                let details: Option<String> =
+                    prompt_optional_data("Enter Details (leave empty to skip):")?;
+                let keybase_username: Option<String> =
+                    prompt_optional_data("Enter Keybase Username (leave empty to skip):")?;
+                let icon_url: Option<String> =
+                    prompt_optional_data("Enter Icon URL (leave empty to skip):")?;
+
+                let identity_keypair = read_keypair_from_path(&identity_keypair_path)?;
+
+                let fields = ValidatorInfoFields {
+                    name: Some(name.trim().to_string()),
+                    website,
+                    details,
+                    keybase_username,
+                    icon_url,
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_publish_validator_info(ctx, &identity_keypair, &fields),
+                )
+                .await?;
+            }
+            VoteCommand::SearchValidatorInfo => {
+                let query: Option<String> = prompt_optional_data(
+                    "Search by name, website or identity (leave empty to list all):",
+                )?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_search_validator_info(ctx, query.as_deref()),
+                )
+                .await?;
+            }
             VoteCommand::CloseVoteAccount => {
                 let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
                 let withdraw_authority_path: PathBuf =
@@ -290,15 +432,11 @@ async fn process_authorize_voter(
     Ok(())
 }
 
-async fn process_sol_withdraw_from_vote_account(
+/// Fetches a vote account and deserializes its state
+async fn fetch_vote_state(
     ctx: &ScillaContext,
     vote_account_pubkey: &Pubkey,
-    authorized_withdrawer: &Keypair,
-    recipient_address: &Pubkey,
-    amount: u64,
-) -> anyhow::Result<()> {
-    let withdrawer_pubkey = authorized_withdrawer.pubkey();
-
+) -> anyhow::Result<(Account, VoteStateV4)> {
     let vote_account = ctx
         .rpc()
         .get_account(vote_account_pubkey)
@@ -312,13 +450,189 @@ async fn process_sol_withdraw_from_vote_account(
     let vote_state = VoteStateV4::deserialize(&vote_account.data, vote_account_pubkey)
         .map_err(|_| anyhow!("Account data could not be deserialized to vote state"))?;
 
-    if withdrawer_pubkey != vote_state.authorized_withdrawer {
+    Ok((vote_account, vote_state))
+}
+
+fn ensure_authorized_withdrawer(vote_state: &VoteStateV4, signer: &Pubkey) -> anyhow::Result<()> {
+    if signer != &vote_state.authorized_withdrawer {
         bail!(
             "Keypair {} is not the authorized withdrawer ({})",
-            withdrawer_pubkey,
+            signer,
             vote_state.authorized_withdrawer
         );
     }
+    Ok(())
+}
+
+async fn process_authorize_withdrawer(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    withdraw_keypair: &Keypair,
+    new_withdraw_keypair: &Keypair,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = withdraw_keypair.pubkey();
+    let new_withdrawer_pubkey = new_withdraw_keypair.pubkey();
+
+    let (_, vote_state) = fetch_vote_state(ctx, vote_account_pubkey).await?;
+    ensure_authorized_withdrawer(&vote_state, &withdrawer_pubkey)?;
+
+    if new_withdrawer_pubkey == withdrawer_pubkey {
+        bail!("{new_withdrawer_pubkey} is already the authorized withdrawer");
+    }
+
+    let authorize_ix = authorize_checked(
+        vote_account_pubkey,
+        &withdrawer_pubkey,
+        &new_withdrawer_pubkey,
+        VoteAuthorize::Withdrawer,
+    );
+
+    let signature = build_and_send_tx(
+        ctx,
+        &[authorize_ix],
+        &[ctx.keypair(), withdraw_keypair, new_withdraw_keypair],
+    )
+    .await?;
+
+    println!(
+        "{} {}",
+        style("Withdraw authority updated! New withdrawer:")
+            .green()
+            .bold(),
+        style(new_withdrawer_pubkey).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_update_commission(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    withdraw_keypair: &Keypair,
+    commission: u8,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = withdraw_keypair.pubkey();
+
+    let ((_, vote_state), epoch_info) =
+        tokio::try_join!(fetch_vote_state(ctx, vote_account_pubkey), async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        })?;
+    ensure_authorized_withdrawer(&vote_state, &withdrawer_pubkey)?;
+
+    // Compare in basis points so a fractional commission (e.g. 5.5%) isn't
+    // mistaken for the whole percent below it
+    let current_bps = vote_state.inflation_rewards_commission_bps;
+    let new_bps = commission as u16 * 100;
+    let current_commission = format_commission_bps(current_bps);
+
+    if new_bps == current_bps {
+        bail!("Commission is already {current_commission}");
+    }
+
+    // Same check as the vote program: increases are allowed up to and
+    // including the epoch midpoint
+    let past_midpoint = epoch_info.slot_index.saturating_mul(2) > epoch_info.slots_in_epoch;
+    if new_bps > current_bps && past_midpoint {
+        println!(
+            "\n{}\n{}",
+            style(format!(
+                "⚠ Commission can only be increased during the first half of an epoch (slot index \
+                 {} of {}).",
+                epoch_info.slot_index, epoch_info.slots_in_epoch
+            ))
+            .yellow()
+            .bold(),
+            style(format!(
+                "Retry in epoch {} — about {} slots from now.",
+                epoch_info.epoch + 1,
+                epoch_info.slots_in_epoch - epoch_info.slot_index
+            ))
+            .yellow()
+        );
+        return Ok(());
+    }
+
+    let commission_ix = update_commission(vote_account_pubkey, &withdrawer_pubkey, commission);
+
+    let signature =
+        build_and_send_tx(ctx, &[commission_ix], &[ctx.keypair(), withdraw_keypair]).await?;
+
+    println!(
+        "{} {}",
+        style(format!(
+            "Commission updated from {current_commission} to {commission}%! Signature:"
+        ))
+        .green()
+        .bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_update_validator_identity(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    withdraw_keypair: &Keypair,
+    identity_keypair: &Keypair,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = withdraw_keypair.pubkey();
+    let identity_pubkey = identity_keypair.pubkey();
+
+    let (_, vote_state) = fetch_vote_state(ctx, vote_account_pubkey).await?;
+    ensure_authorized_withdrawer(&vote_state, &withdrawer_pubkey)?;
+
+    if identity_pubkey == vote_state.node_pubkey {
+        bail!("{identity_pubkey} is already the validator identity");
+    }
+    if &identity_pubkey == vote_account_pubkey {
+        bail!("Validator identity cannot be the vote account itself");
+    }
+
+    let identity_ix =
+        update_validator_identity(vote_account_pubkey, &withdrawer_pubkey, &identity_pubkey);
+
+    let signature = build_and_send_tx(
+        ctx,
+        &[identity_ix],
+        &[ctx.keypair(), withdraw_keypair, identity_keypair],
+    )
+    .await?;
+
+    println!(
+        "{} {} → {}",
+        style("Validator identity updated:").green().bold(),
+        style(vote_state.node_pubkey).yellow(),
+        style(identity_pubkey).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_sol_withdraw_from_vote_account(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    authorized_withdrawer: &Keypair,
+    recipient_address: &Pubkey,
+    amount: u64,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = authorized_withdrawer.pubkey();
+
+    let (_, vote_state) = fetch_vote_state(ctx, vote_account_pubkey).await?;
+    ensure_authorized_withdrawer(&vote_state, &withdrawer_pubkey)?;
 
     let withdraw_ix = withdraw(
         vote_account_pubkey,
@@ -395,18 +709,7 @@ async fn process_fetch_vote_account(
     ctx: &ScillaContext,
     vote_account_pubkey: &Pubkey,
 ) -> anyhow::Result<()> {
-    let vote_account = ctx
-        .rpc()
-        .get_account(vote_account_pubkey)
-        .await
-        .map_err(|_| anyhow!("{vote_account_pubkey} account does not exist"))?;
-
-    if vote_account.owner != solana_vote_interface::program::id() {
-        bail!("{vote_account_pubkey} is not a vote account");
-    }
-
-    let vote_state = VoteStateV4::deserialize(&vote_account.data, vote_account_pubkey)
-        .map_err(|_| anyhow!("Account data could not be deserialized to vote state"))?;
+    let (vote_account, vote_state) = fetch_vote_state(ctx, vote_account_pubkey).await?;
 
     let balance_sol = lamports_to_sol(vote_account.lamports);
 
@@ -449,9 +752,8 @@ async fn process_fetch_vote_account(
         .add_row(vec![Cell::new("Credits"), Cell::new(vote_state.credits())])
         .add_row(vec![
             Cell::new("Commission"),
-            Cell::new(format!(
-                "{}%",
-                vote_state.inflation_rewards_commission_bps / 100
+            Cell::new(format_commission_bps(
+                vote_state.inflation_rewards_commission_bps,
             )),
         ])
         .add_row(vec![Cell::new("Root Slot"), Cell::new(root_slot)])
@@ -468,3 +770,360 @@ async fn process_fetch_vote_account(
 
     Ok(())
 }
+
+/// Groups a landed vote's latency into a display bucket keyed by its lower
+/// bound. Votes landing within the grace period earn full credits.
+fn latency_bucket(latency: u8) -> (u8, &'static str) {
+    match latency {
+        0 => (0, "unrecorded"),
+        1..=2 => (1, "1-2 slots (full credit)"),
+        3..=4 => (3, "3-4 slots"),
+        5..=8 => (5, "5-8 slots"),
+        9..=16 => (9, "9-16 slots"),
+        _ => (17, "17+ slots"),
+    }
+}
+
+async fn process_vote_account_health(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+) -> anyhow::Result<()> {
+    let ((_, vote_state), epoch_info, vote_accounts) = tokio::try_join!(
+        fetch_vote_state(ctx, vote_account_pubkey),
+        async {
+            ctx.rpc()
+                .get_epoch_info()
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        async {
+            ctx.rpc()
+                .get_vote_accounts_with_config(RpcGetVoteAccountsConfig {
+                    keep_unstaked_delinquents: Some(true),
+                    ..RpcGetVoteAccountsConfig::default()
+                })
+                .await
+                .map_err(anyhow::Error::from)
+        }
+    )?;
+
+    let vote_account_str = vote_account_pubkey.to_string();
+    let rpc_status = if vote_accounts
+        .current
+        .iter()
+        .any(|v| v.vote_pubkey == vote_account_str)
+    {
+        style("Current").green()
+    } else if vote_accounts
+        .delinquent
+        .iter()
+        .any(|v| v.vote_pubkey == vote_account_str)
+    {
+        style("Delinquent").red()
+    } else {
+        style("Not found in vote accounts").yellow()
+    };
+
+    // Best credits earned by any validator, per epoch
+    let mut cluster_max: BTreeMap<u64, u64> = BTreeMap::new();
+    for info in vote_accounts
+        .current
+        .iter()
+        .chain(&vote_accounts.delinquent)
+    {
+        for (epoch, credits, prev_credits) in &info.epoch_credits {
+            let earned = credits.saturating_sub(*prev_credits);
+            let max = cluster_max.entry(*epoch).or_default();
+            *max = (*max).max(earned);
+        }
+    }
+
+    let current_slot = epoch_info.absolute_slot;
+    let last_vote_slot = vote_state.votes.back().map(|vote| vote.lockout.slot());
+    let slots_behind = last_vote_slot.map(|slot| current_slot.saturating_sub(slot));
+
+    let mut summary_table = Table::new();
+    summary_table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Validator Identity"),
+            Cell::new(vote_state.node_pubkey),
+        ])
+        .add_row(vec![Cell::new("Cluster Status"), Cell::new(rpc_status)])
+        .add_row(vec![Cell::new("Current Slot"), Cell::new(current_slot)])
+        .add_row(vec![
+            Cell::new("Last Vote Slot"),
+            Cell::new(
+                last_vote_slot
+                    .map(|slot| slot.to_string())
+                    .unwrap_or_else(|| "~".to_string()),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Slots Behind"),
+            Cell::new(match slots_behind {
+                Some(distance) if distance > DELINQUENT_VALIDATOR_SLOT_DISTANCE => {
+                    style(format!("{distance} (delinquent)")).red()
+                }
+                Some(distance) => style(distance.to_string()).green(),
+                None => style("no votes".to_string()).yellow(),
+            }),
+        ])
+        .add_row(vec![
+            Cell::new("Root Slot"),
+            Cell::new(
+                vote_state
+                    .root_slot
+                    .map(|slot| slot.to_string())
+                    .unwrap_or_else(|| "~".to_string()),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Total Credits"),
+            Cell::new(vote_state.credits()),
+        ]);
+
+    println!("\n{}", style("VOTE ACCOUNT HEALTH").green().bold());
+    println!("{summary_table}");
+
+    if !vote_state.epoch_credits.is_empty() {
+        let mut credits_table = Table::new();
+        credits_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Credits Earned").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Cluster Max").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Efficiency").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Est. Missed Votes").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (epoch, credits, prev_credits) in vote_state
+            .epoch_credits
+            .iter()
+            .rev()
+            .take(DEFAULT_EPOCH_LIMIT)
+        {
+            let earned = credits.saturating_sub(*prev_credits);
+            let row = match cluster_max.get(epoch) {
+                Some(&max) if max > 0 => {
+                    let missed = max.saturating_sub(earned) / VOTE_CREDITS_MAXIMUM_PER_SLOT as u64;
+                    vec![
+                        Cell::new(epoch),
+                        Cell::new(earned),
+                        Cell::new(max),
+                        Cell::new(format!("{:.2}%", earned as f64 / max as f64 * 100.0)),
+                        Cell::new(missed),
+                    ]
+                }
+                _ => vec![
+                    Cell::new(epoch),
+                    Cell::new(earned),
+                    Cell::new("-"),
+                    Cell::new("-"),
+                    Cell::new("-"),
+                ],
+            };
+            credits_table.add_row(row);
+        }
+
+        println!("\n{}", style("EPOCH CREDITS").green().bold());
+        println!("{credits_table}");
+    }
+
+    if !vote_state.votes.is_empty() {
+        let mut buckets: BTreeMap<u8, (&str, usize)> = BTreeMap::new();
+        for vote in &vote_state.votes {
+            let (lower_bound, label) = latency_bucket(vote.latency);
+            buckets.entry(lower_bound).or_insert((label, 0)).1 += 1;
+        }
+
+        let total_votes = vote_state.votes.len();
+        let average_latency = vote_state
+            .votes
+            .iter()
+            .map(|vote| vote.latency as f64)
+            .sum::<f64>()
+            / total_votes as f64;
+
+        let mut latency_table = Table::new();
+        latency_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Latency").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Votes").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Share").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (label, count) in buckets.values() {
+            latency_table.add_row(vec![
+                Cell::new(label),
+                Cell::new(count),
+                Cell::new(format!(
+                    "{:.1}%",
+                    *count as f64 / total_votes as f64 * 100.0
+                )),
+            ]);
+        }
+
+        println!(
+            "\n{} {}",
+            style("VOTE LATENCY (lockout tower)").green().bold(),
+            style(format!("average {average_latency:.2} slots")).dim()
+        );
+        println!("{latency_table}");
+    }
+
+    let mut voters_table = Table::new();
+    voters_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Since Epoch").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Authorized Voter").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (epoch, voter) in vote_state.authorized_voters.iter() {
+        voters_table.add_row(vec![Cell::new(epoch), Cell::new(voter)]);
+    }
+
+    // The vote state only keeps the current voter and those scheduled for
+    // future epochs; earlier voters are purged once their epoch passes
+    println!(
+        "\n{}",
+        style("CURRENT/SCHEDULED AUTHORIZED VOTERS").green().bold()
+    );
+    println!("{voters_table}");
+    println!(
+        "{}",
+        style("Past authorized voters are not kept in the vote account and can't be shown.").dim()
+    );
+
+    Ok(())
+}
+
+async fn process_publish_validator_info(
+    ctx: &ScillaContext,
+    identity_keypair: &Keypair,
+    fields: &ValidatorInfoFields,
+) -> anyhow::Result<()> {
+    let identity_pubkey = identity_keypair.pubkey();
+    let validator_info = fields.to_validator_info()?;
+
+    let existing = fetch_all_validator_info(ctx)
+        .await?
+        .into_iter()
+        .find(|info| info.identity == identity_pubkey);
+
+    let keys = vec![(validator_info_config_id(), false), (identity_pubkey, true)];
+
+    let (info_pubkey, signature) = match existing {
+        Some(existing) => {
+            let store_ix = store(&existing.info_pubkey, false, keys, &validator_info);
+            let signature =
+                build_and_send_tx(ctx, &[store_ix], &[ctx.keypair(), identity_keypair]).await?;
+            (existing.info_pubkey, signature)
+        }
+        None => {
+            let info_keypair = Keypair::new();
+            let info_pubkey = info_keypair.pubkey();
+
+            let space = ValidatorInfo::max_space()
+                + bincode::serialized_size(&ConfigKeys { keys: keys.clone() })?;
+            let lamports = ctx
+                .rpc()
+                .get_minimum_balance_for_rent_exemption(space as usize)
+                .await?;
+
+            let mut instructions = create_account_with_max_config_space::<ValidatorInfo>(
+                ctx.pubkey(),
+                &info_pubkey,
+                lamports,
+                ValidatorInfo::max_space(),
+                keys.clone(),
+            );
+            instructions.push(store(&info_pubkey, true, keys, &validator_info));
+
+            let signature = build_and_send_tx(
+                ctx,
+                &instructions,
+                &[ctx.keypair(), &info_keypair, identity_keypair],
+            )
+            .await?;
+            (info_pubkey, signature)
+        }
+    };
+
+    println!(
+        "{} {}",
+        style("Validator info published for").green().bold(),
+        style(identity_pubkey).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Info account:").green(),
+        style(info_pubkey).cyan()
+    );
+    println!(
+        "{} {}",
+        style("Signature:").green().bold(),
+        style(signature).cyan()
+    );
+
+    Ok(())
+}
+
+async fn process_search_validator_info(
+    ctx: &ScillaContext,
+    query: Option<&str>,
+) -> anyhow::Result<()> {
+    let query = query.map(str::to_lowercase);
+
+    let mut infos: Vec<_> = fetch_all_validator_info(ctx)
+        .await?
+        .into_iter()
+        .filter(|info| {
+            let Some(query) = &query else {
+                return true;
+            };
+            let fields = &info.fields;
+            info.identity.to_string().to_lowercase().contains(query)
+                || [&fields.name, &fields.website, &fields.keybase_username]
+                    .into_iter()
+                    .flatten()
+                    .any(|value| value.to_lowercase().contains(query))
+        })
+        .collect();
+
+    if infos.is_empty() {
+        println!("\n{}", style("No matching validator info found").yellow());
+        return Ok(());
+    }
+
+    infos.sort_by(|a, b| a.fields.name.cmp(&b.fields.name));
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Website").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Keybase").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Details").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for info in &infos {
+        let fields = &info.fields;
+        table.add_row(vec![
+            Cell::new(info.identity),
+            Cell::new(fields.name.as_deref().unwrap_or("-")),
+            Cell::new(fields.website.as_deref().unwrap_or("-")),
+            Cell::new(fields.keybase_username.as_deref().unwrap_or("-")),
+            Cell::new(fields.details.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!(
+        "\n{} {}",
+        style("VALIDATOR INFO").green().bold(),
+        style(format!("({} records)", infos.len())).dim()
+    );
+    println!("{table}");
+
+    Ok(())
+}
@@ -3,9 +3,10 @@ use {
         constants::{DEFAULT_KEYPAIR_PATH, DEVNET_RPC, SCILLA_CONFIG_RELATIVE_PATH},
         error::ScillaError,
     },
-    serde::{Deserialize, Serialize},
+    serde::{Deserialize, Serialize, Serializer},
     solana_commitment_config::CommitmentLevel,
-    std::{env::home_dir, fs, path::PathBuf},
+    solana_pubkey::Pubkey,
+    std::{env::home_dir, fs, path::PathBuf, str::FromStr},
 };
 
 pub fn scilla_config_path() -> PathBuf {
@@ -33,6 +34,28 @@ where
     Ok(expand_tilde(&s))
 }
 
+// Pubkeys are written as base58 strings rather than byte arrays
+fn serialize_pubkeys<S>(pubkeys: &[Pubkey], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(pubkeys.iter().map(Pubkey::to_string))
+}
+
+fn deserialize_pubkeys<'de, D>(deserializer: D) -> Result<Vec<Pubkey>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values: Vec<String> = Deserialize::deserialize(deserializer)?;
+    values
+        .iter()
+        .map(|value| {
+            Pubkey::from_str(value)
+                .map_err(|_| serde::de::Error::custom(format!("invalid pubkey: {value}")))
+        })
+        .collect()
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "kebab-case")]
 pub struct ScillaConfig {
@@ -40,6 +63,17 @@ pub struct ScillaConfig {
     pub commitment_level: CommitmentLevel,
     #[serde(deserialize_with = "deserialize_path_with_tilde")]
     pub keypair_path: PathBuf,
+    /// Vote accounts or identities of the validators the dashboard follows
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_pubkeys",
+        deserialize_with = "deserialize_pubkeys"
+    )]
+    pub validators: Vec<Pubkey>,
+    /// Extra RPC endpoints the health check compares against `rpc_url`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc_endpoints: Vec<String>,
 }
 
 impl Default for ScillaConfig {
@@ -52,6 +86,8 @@ impl Default for ScillaConfig {
             rpc_url: DEVNET_RPC.to_string(),
             commitment_level: CommitmentLevel::Confirmed,
             keypair_path: default_keypair_path,
+            validators: Vec::new(),
+            rpc_endpoints: Vec::new(),
         }
     }
 }
@@ -150,5 +186,36 @@ commitment-level = "confirmed"
         assert_eq!(config.rpc_url, "https://api.mainnet-beta.solana.com");
         assert_eq!(config.commitment_level, CommitmentLevel::Confirmed);
         assert_eq!(config.keypair_path, home.join("my/key.json"));
+        assert!(config.validators.is_empty());
+        assert!(config.rpc_endpoints.is_empty());
+    }
+
+    #[test]
+    fn test_validators_round_trip() {
+        let validator = Pubkey::new_unique();
+        let config = ScillaConfig {
+            validators: vec![validator],
+            ..ScillaConfig::default()
+        };
+
+        let toml_string = toml::to_string_pretty(&config).expect("Config should serialize");
+        assert!(toml_string.contains(&validator.to_string()));
+
+        let loaded: ScillaConfig = toml::from_str(&toml_string).expect("Config should parse");
+        assert_eq!(loaded.validators, vec![validator]);
+    }
+
+    #[test]
+    fn test_rpc_endpoints_round_trip() {
+        let config = ScillaConfig {
+            rpc_endpoints: vec!["https://api.devnet.solana.com".to_string()],
+            ..ScillaConfig::default()
+        };
+
+        let toml_string = toml::to_string_pretty(&config).expect("Config should serialize");
+        assert!(toml_string.contains("rpc-endpoints"));
+
+        let loaded: ScillaConfig = toml::from_str(&toml_string).expect("Config should parse");
+        assert_eq!(loaded.rpc_endpoints, config.rpc_endpoints);
     }
 }
@@ -17,3 +17,68 @@ pub const DEFAULT_EPOCH_LIMIT: usize = 10;
 pub const STAKE_HISTORY_SYSVAR_ADDR: &str = "SysvarStakeHistory1111111111111111111111111";
 
 pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+pub const DEFAULT_MS_PER_SLOT: u64 = 400;
+
+pub const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+pub const DEFAULT_REWARD_EPOCHS: u64 = 5;
+
+pub const VALIDATOR_INFO_CONFIG_ID: &str = "Va1idator1nfo111111111111111111111111111111";
+
+pub const MAX_VALIDATOR_INFO_BYTES: u64 = 576;
+
+pub const MAX_SHORT_FIELD_LENGTH: usize = 80;
+
+pub const MAX_LONG_FIELD_LENGTH: usize = 300;
+
+pub const PERFORMANCE_SAMPLE_LIMIT: usize = 30;
+
+pub const LEADER_WINDOW_LIMIT: usize = 20;
+
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+pub const MEMO_V1_PROGRAM_ID: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+
+pub const DEFAULT_SIGNATURE_PAGE_SIZE: usize = 20;
+
+pub const MAX_SIGNATURE_PAGE_SIZE: usize = 1000;
+
+pub const LOOKUP_TABLE_EXTEND_CHUNK_SIZE: usize = 20;
+
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+pub const CONFIRMATION_POLL_INTERVAL_MS: u64 = 500;
+
+pub const DEFAULT_SYSVAR_ENTRIES: usize = 20;
+
+pub const DEFAULT_SLOT_HISTORY_WINDOW: u64 = 1000;
+
+pub const STAKE_CHART_WIDTH: usize = 40;
+
+pub const FORECAST_MAX_EPOCHS: u64 = 100;
+
+pub const REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID: &str =
+    "GwtDQBghCTBgmX2cpEGNPxTEBUTQRaDMGTr5qychdGMj";
+
+pub const PROGRAM_WRITE_PARALLELISM: usize = 16;
+
+pub const PROGRAM_WRITE_MAX_ROUNDS: usize = 5;
+
+pub const WATCH_POLL_INTERVAL_MS: u64 = 2000;
+
+pub const WATCH_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+pub const DASHBOARD_REFRESH_SECS: u64 = 5;
+
+pub const DASHBOARD_BAR_WIDTH: usize = 40;
+
+pub const EPOCH_PROGRESS_BAR_WIDTH: usize = 40;
+
+pub const HEALTH_CHECK_TIMEOUT_SECS: u64 = 10;
+
+pub const NODE_VERSION_BAR_WIDTH: usize = 20;
@@ -4,12 +4,19 @@ use {
     solana_keypair::{EncodableKey, Keypair, Signer},
     solana_pubkey::Pubkey,
     solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    std::sync::Mutex,
 };
 
 pub struct ScillaContext {
     rpc_client: RpcClient,
     keypair: Keypair,
     pubkey: Pubkey,
+    /// Validators followed on the cluster dashboard
+    validators: Vec<Pubkey>,
+    /// Extra RPC endpoints compared by the health check
+    rpc_endpoints: Vec<String>,
+    /// Lookup table that outgoing transactions are compiled against
+    lookup_table: Mutex<Option<Pubkey>>,
 }
 
 impl ScillaContext {
@@ -24,6 +31,22 @@ impl ScillaContext {
     pub fn pubkey(&self) -> &Pubkey {
         &self.pubkey
     }
+
+    pub fn validators(&self) -> &[Pubkey] {
+        &self.validators
+    }
+
+    pub fn rpc_endpoints(&self) -> &[String] {
+        &self.rpc_endpoints
+    }
+
+    pub fn lookup_table(&self) -> Option<Pubkey> {
+        *self.lookup_table.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn set_lookup_table(&self, lookup_table: Option<Pubkey>) {
+        *self.lookup_table.lock().unwrap_or_else(|e| e.into_inner()) = lookup_table;
+    }
 }
 
 impl ScillaContext {
@@ -51,6 +74,9 @@ impl ScillaContext {
             rpc_client,
             keypair,
             pubkey,
+            validators: config.validators,
+            rpc_endpoints: config.rpc_endpoints,
+            lookup_table: Mutex::new(None),
         })
     }
 }
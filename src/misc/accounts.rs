@@ -0,0 +1,799 @@
+use {
+    crate::misc::{
+        helpers::{bincode_deserialize, format_commission_bps, lamports_to_sol},
+        programs::program_label,
+        sysvars::{format_unix_timestamp, sysvar_fields, sysvar_name},
+        token::{
+            account_state_label, format_token_amount, token_program_of, unpack_mint,
+            unpack_token_account,
+        },
+    },
+    solana_account::Account,
+    solana_address_lookup_table_interface::state::AddressLookupTable,
+    solana_loader_v3_interface::state::UpgradeableLoaderState,
+    solana_nonce::{state::State as NonceState, versions::Versions},
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{
+        address_lookup_table, bpf_loader_upgradeable, stake, system_program, sysvar, vote,
+    },
+    solana_stake_interface::state::{Meta, StakeStateV2},
+    solana_vote_interface::state::VoteStateV4,
+};
+
+/// Account data resolved to a type name and its fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAccount {
+    pub kind: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl DecodedAccount {
+    fn new(kind: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    fn field(mut self, label: &str, value: impl ToString) -> Self {
+        self.fields.push((label.to_string(), value.to_string()));
+        self
+    }
+}
+
+fn optional_pubkey(pubkey: Option<Pubkey>) -> String {
+    pubkey.map_or_else(|| "None".to_string(), |pubkey| pubkey.to_string())
+}
+
+fn epoch_or_none(epoch: u64) -> String {
+    if epoch == u64::MAX {
+        "None".to_string()
+    } else {
+        epoch.to_string()
+    }
+}
+
+/// Decodes account data for the owner programs Scilla understands.
+///
+/// Returns `None` when the owner is not recognised or the data doesn't match
+/// the layout its owner implies.
+pub fn decode_account(pubkey: &Pubkey, account: &Account) -> Option<DecodedAccount> {
+    let data = account.data.as_slice();
+
+    let decoded = match account.owner {
+        owner if owner == sysvar::id() => {
+            let name = sysvar_name(pubkey)?;
+            sysvar_fields(pubkey, data).ok()?.into_iter().fold(
+                DecodedAccount::new(format!("{name} Sysvar")),
+                |decoded, (label, value)| decoded.field(&label, value),
+            )
+        }
+        owner if owner == stake::id() => decode_stake(data)?,
+        owner if owner == vote::id() => decode_vote(pubkey, data)?,
+        owner if owner == system_program::id() && !data.is_empty() => decode_nonce(data)?,
+        owner if owner == address_lookup_table::id() => decode_lookup_table(data)?,
+        owner if owner == bpf_loader_upgradeable::id() => decode_upgradeable_loader(data)?,
+        _ if token_program_of(account).is_some() => decode_token(account)?,
+        _ => return None,
+    };
+
+    Some(decoded)
+}
+
+fn with_stake_meta(decoded: DecodedAccount, meta: &Meta) -> DecodedAccount {
+    let lockup = &meta.lockup;
+    let decoded = decoded
+        .field(
+            "Rent-Exempt Reserve",
+            format!("{} SOL", lamports_to_sol(meta.rent_exempt_reserve)),
+        )
+        .field("Stake Authority", meta.authorized.staker)
+        .field("Withdraw Authority", meta.authorized.withdrawer);
+
+    if lockup.unix_timestamp == 0 && lockup.epoch == 0 {
+        return decoded.field("Lockup", "None");
+    }
+    decoded
+        .field(
+            "Lockup Until",
+            format!(
+                "{} / epoch {}",
+                format_unix_timestamp(lockup.unix_timestamp),
+                lockup.epoch
+            ),
+        )
+        .field("Lockup Custodian", lockup.custodian)
+}
+
+fn decode_stake(data: &[u8]) -> Option<DecodedAccount> {
+    let state: StakeStateV2 = bincode_deserialize(data, "stake account data").ok()?;
+
+    let decoded = match state {
+        StakeStateV2::Uninitialized => {
+            DecodedAccount::new("Stake Account").field("State", "Uninitialized")
+        }
+        StakeStateV2::RewardsPool => {
+            DecodedAccount::new("Stake Account").field("State", "Rewards Pool")
+        }
+        StakeStateV2::Initialized(meta) => with_stake_meta(
+            DecodedAccount::new("Stake Account").field("State", "Initialized"),
+            &meta,
+        ),
+        StakeStateV2::Stake(meta, stake, _) => with_stake_meta(
+            DecodedAccount::new("Stake Account").field("State", "Delegated"),
+            &meta,
+        )
+        .field("Delegated Vote Account", stake.delegation.voter_pubkey)
+        .field(
+            "Delegated Stake",
+            format!("{} SOL", lamports_to_sol(stake.delegation.stake)),
+        )
+        .field(
+            "Activation Epoch",
+            epoch_or_none(stake.delegation.activation_epoch),
+        )
+        .field(
+            "Deactivation Epoch",
+            epoch_or_none(stake.delegation.deactivation_epoch),
+        )
+        .field("Credits Observed", stake.credits_observed),
+    };
+
+    Some(decoded)
+}
+
+fn decode_vote(pubkey: &Pubkey, data: &[u8]) -> Option<DecodedAccount> {
+    let vote_state = VoteStateV4::deserialize(data, pubkey).ok()?;
+
+    let authorized_voter = vote_state
+        .authorized_voters
+        .last()
+        .map(|(_, voter)| voter.to_string())
+        .unwrap_or_else(|| "None".to_string());
+
+    Some(
+        DecodedAccount::new("Vote Account")
+            .field("Validator Identity", vote_state.node_pubkey)
+            .field("Vote Authority", authorized_voter)
+            .field("Withdraw Authority", vote_state.authorized_withdrawer)
+            .field(
+                "Commission",
+                format_commission_bps(vote_state.inflation_rewards_commission_bps),
+            )
+            .field("Credits", vote_state.credits())
+            .field(
+                "Root Slot",
+                vote_state
+                    .root_slot
+                    .map_or_else(|| "None".to_string(), |slot| slot.to_string()),
+            )
+            .field(
+                "Last Vote Slot",
+                vote_state.votes.back().map_or_else(
+                    || "None".to_string(),
+                    |vote| vote.lockout.slot().to_string(),
+                ),
+            )
+            .field(
+                "Recent Timestamp",
+                format!(
+                    "{} from slot {}",
+                    format_unix_timestamp(vote_state.last_timestamp.timestamp),
+                    vote_state.last_timestamp.slot
+                ),
+            ),
+    )
+}
+
+fn decode_nonce(data: &[u8]) -> Option<DecodedAccount> {
+    let versions: Versions = bincode_deserialize(data, "nonce account data").ok()?;
+
+    let decoded = match versions.state() {
+        NonceState::Uninitialized => {
+            DecodedAccount::new("Nonce Account").field("State", "Uninitialized")
+        }
+        NonceState::Initialized(nonce) => DecodedAccount::new("Nonce Account")
+            .field("State", "Initialized")
+            .field("Authority", nonce.authority)
+            .field("Nonce Blockhash", nonce.blockhash())
+            .field(
+                "Lamports per Signature",
+                nonce.fee_calculator.lamports_per_signature,
+            ),
+    };
+
+    Some(decoded)
+}
+
+fn decode_lookup_table(data: &[u8]) -> Option<DecodedAccount> {
+    let table = AddressLookupTable::deserialize(data).ok()?;
+    let status = if table.meta.deactivation_slot == u64::MAX {
+        "Active".to_string()
+    } else {
+        format!("Deactivated at slot {}", table.meta.deactivation_slot)
+    };
+
+    Some(
+        DecodedAccount::new("Address Lookup Table")
+            .field("Status", status)
+            .field(
+                "Authority",
+                table
+                    .meta
+                    .authority
+                    .map_or_else(|| "None (frozen)".to_string(), |pubkey| pubkey.to_string()),
+            )
+            .field("Addresses", table.addresses.len())
+            .field("Last Extended Slot", table.meta.last_extended_slot),
+    )
+}
+
+fn decode_upgradeable_loader(data: &[u8]) -> Option<DecodedAccount> {
+    let state: UpgradeableLoaderState =
+        bincode_deserialize(data, "upgradeable loader state").ok()?;
+
+    let decoded = match state {
+        UpgradeableLoaderState::Uninitialized => {
+            DecodedAccount::new("Upgradeable Loader Account").field("State", "Uninitialized")
+        }
+        UpgradeableLoaderState::Buffer { authority_address } => {
+            DecodedAccount::new("Program Buffer")
+                .field("Authority", optional_pubkey(authority_address))
+                .field(
+                    "Data Length",
+                    data.len()
+                        .saturating_sub(UpgradeableLoaderState::size_of_buffer_metadata()),
+                )
+        }
+        UpgradeableLoaderState::Program {
+            programdata_address,
+        } => DecodedAccount::new("Program").field("Program Data Account", programdata_address),
+        UpgradeableLoaderState::ProgramData {
+            slot,
+            upgrade_authority_address,
+        } => DecodedAccount::new("Program Data")
+            .field("Last Deployed Slot", slot)
+            .field(
+                "Upgrade Authority",
+                upgrade_authority_address.map_or_else(
+                    || "None (immutable)".to_string(),
+                    |pubkey| pubkey.to_string(),
+                ),
+            )
+            .field(
+                "Program Length",
+                data.len()
+                    .saturating_sub(UpgradeableLoaderState::size_of_programdata_metadata()),
+            ),
+    };
+
+    Some(decoded)
+}
+
+fn decode_token(account: &Account) -> Option<DecodedAccount> {
+    let program = program_label(&token_program_of(account)?);
+
+    if let Ok(mint) = unpack_mint(&account.data) {
+        let mut decoded = DecodedAccount::new(format!("Mint ({program})"))
+            .field(
+                "Supply",
+                format_token_amount(mint.mint.supply, mint.mint.decimals),
+            )
+            .field("Decimals", mint.mint.decimals)
+            .field(
+                "Mint Authority",
+                optional_pubkey(mint.mint.mint_authority.into()),
+            )
+            .field(
+                "Freeze Authority",
+                optional_pubkey(mint.mint.freeze_authority.into()),
+            );
+        if let Some(metadata) = &mint.metadata {
+            decoded = decoded
+                .field("Name", &metadata.name)
+                .field("Symbol", &metadata.symbol);
+        }
+        if !mint.extensions.is_empty() {
+            decoded = decoded.field("Extensions", format!("{:?}", mint.extensions));
+        }
+        return Some(decoded);
+    }
+
+    let info = unpack_token_account(&account.data).ok()?;
+    let token = info.account;
+    let mut decoded = DecodedAccount::new(format!("Token Account ({program})"))
+        .field("Mint", token.mint)
+        .field("Owner", token.owner)
+        .field("Amount (raw)", token.amount)
+        .field("State", account_state_label(token.state))
+        .field("Delegate", optional_pubkey(token.delegate.into()))
+        .field(
+            "Close Authority",
+            optional_pubkey(token.close_authority.into()),
+        );
+    if token.is_native() {
+        decoded = decoded.field("Native", "Wrapped SOL");
+    }
+    if !info.extensions.is_empty() {
+        decoded = decoded.field("Extensions", format!("{:?}", info.extensions));
+    }
+
+    Some(decoded)
+}
+
+/// One property of an account that differs between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl AccountChange {
+    fn new(field: &str, before: impl ToString, after: impl ToString) -> Self {
+        Self {
+            field: field.to_string(),
+            before: before.to_string(),
+            after: after.to_string(),
+        }
+    }
+}
+
+/// Differences between two snapshots of an account. Data owned by a program
+/// Scilla can decode is compared field by field; other data is summarised by
+/// size and the number of bytes that changed.
+pub fn account_changes(
+    pubkey: &Pubkey,
+    before: Option<&Account>,
+    after: Option<&Account>,
+) -> Vec<AccountChange> {
+    let (before, after) = match (before, after) {
+        (None, None) => return Vec::new(),
+        (None, Some(after)) => {
+            return vec![AccountChange::new(
+                "Account",
+                "None",
+                format!(
+                    "created with {} SOL and {} bytes, owned by {}",
+                    lamports_to_sol(after.lamports),
+                    after.data.len(),
+                    program_label(&after.owner)
+                ),
+            )];
+        }
+        (Some(before), None) => {
+            return vec![AccountChange::new(
+                "Account",
+                format!("{} SOL", lamports_to_sol(before.lamports)),
+                "closed",
+            )];
+        }
+        (Some(before), Some(after)) => (before, after),
+    };
+
+    let mut changes = Vec::new();
+    if before.lamports != after.lamports {
+        changes.push(AccountChange::new(
+            "Balance",
+            format!("{} SOL", lamports_to_sol(before.lamports)),
+            format!(
+                "{} SOL ({:+} lamports)",
+                lamports_to_sol(after.lamports),
+                after.lamports as i128 - before.lamports as i128
+            ),
+        ));
+    }
+    if before.owner != after.owner {
+        changes.push(AccountChange::new(
+            "Owner",
+            program_label(&before.owner),
+            program_label(&after.owner),
+        ));
+    }
+    if before.executable != after.executable {
+        changes.push(AccountChange::new(
+            "Executable",
+            before.executable,
+            after.executable,
+        ));
+    }
+    if before.data == after.data {
+        return changes;
+    }
+
+    let field_changes = match (
+        decode_account(pubkey, before),
+        decode_account(pubkey, after),
+    ) {
+        (Some(old), Some(new)) if old.kind == new.kind => decoded_changes(&old, &new),
+        _ => Vec::new(),
+    };
+    // Decoders only show the fields worth reading, so a change may be
+    // invisible to them
+    if field_changes.is_empty() {
+        let changed_bytes = before
+            .data
+            .iter()
+            .zip(&after.data)
+            .filter(|(old, new)| old != new)
+            .count()
+            + before.data.len().abs_diff(after.data.len());
+        changes.push(AccountChange::new(
+            "Data",
+            format!("{} bytes", before.data.len()),
+            format!("{} bytes, {changed_bytes} changed", after.data.len()),
+        ));
+    }
+    changes.extend(field_changes);
+
+    changes
+}
+
+fn decoded_changes(old: &DecodedAccount, new: &DecodedAccount) -> Vec<AccountChange> {
+    let lookup = |decoded: &DecodedAccount, label: &str| {
+        decoded
+            .fields
+            .iter()
+            .find(|(field, _)| field == label)
+            .map(|(_, value)| value.clone())
+    };
+
+    let mut changes: Vec<AccountChange> = new
+        .fields
+        .iter()
+        .filter(|(label, value)| lookup(old, label).as_ref() != Some(value))
+        .map(|(label, value)| {
+            AccountChange::new(
+                label,
+                lookup(old, label).unwrap_or("None".to_string()),
+                value,
+            )
+        })
+        .collect();
+    changes.extend(
+        old.fields
+            .iter()
+            .filter(|(label, _)| lookup(new, label).is_none())
+            .map(|(label, value)| AccountChange::new(label, value, "None")),
+    );
+
+    changes
+}
+
+/// Classic hex dump: offset, 16 bytes per row, and the printable ASCII
+pub fn hex_dump(data: &[u8]) -> String {
+    data.chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08x}  {:<47}  |{ascii}|", row * 16, hex.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_nonce::state::Data};
+
+    #[test]
+    fn test_decode_nonce_account() {
+        let authority = Pubkey::new_unique();
+        let data = Data::new(authority, Default::default(), 5000);
+        let versions = Versions::new(NonceState::Initialized(data));
+        let account = Account {
+            lamports: 1_447_680,
+            data: bincode::serialize(&versions).unwrap(),
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let decoded = decode_account(&Pubkey::new_unique(), &account).unwrap();
+        assert_eq!(decoded.kind, "Nonce Account");
+        assert!(
+            decoded
+                .fields
+                .contains(&("Authority".to_string(), authority.to_string()))
+        );
+        assert!(
+            decoded
+                .fields
+                .contains(&("Lamports per Signature".to_string(), "5000".to_string()))
+        );
+    }
+
+    fn field<'a>(decoded: &'a DecodedAccount, label: &str) -> Option<&'a str> {
+        decoded
+            .fields
+            .iter()
+            .find(|(name, _)| name == label)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn owned_by(owner: Pubkey, data: Vec<u8>) -> Account {
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_decode_stake_account() {
+        use solana_stake_interface::{
+            stake_flags::StakeFlags,
+            state::{Authorized, Delegation, Stake},
+        };
+
+        let (authority, voter) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let state = StakeStateV2::Stake(
+            Meta {
+                authorized: Authorized::auto(&authority),
+                ..Meta::default()
+            },
+            Stake {
+                delegation: Delegation {
+                    voter_pubkey: voter,
+                    stake: 2_000_000_000,
+                    activation_epoch: 7,
+                    ..Delegation::default()
+                },
+                credits_observed: 42,
+            },
+            StakeFlags::empty(),
+        );
+        let account = owned_by(stake::id(), bincode::serialize(&state).unwrap());
+
+        let decoded = decode_account(&Pubkey::new_unique(), &account).unwrap();
+        assert_eq!(decoded.kind, "Stake Account");
+        assert_eq!(field(&decoded, "State"), Some("Delegated"));
+        let voter = voter.to_string();
+        assert_eq!(
+            field(&decoded, "Delegated Vote Account"),
+            Some(voter.as_str())
+        );
+        assert_eq!(field(&decoded, "Delegated Stake"), Some("2 SOL"));
+        assert_eq!(field(&decoded, "Activation Epoch"), Some("7"));
+        assert_eq!(field(&decoded, "Deactivation Epoch"), Some("None"));
+        assert_eq!(field(&decoded, "Lockup"), Some("None"));
+    }
+
+    #[test]
+    fn test_decode_vote_account() {
+        use solana_vote_interface::state::{VoteInit, VoteStateVersions};
+
+        let (vote_pubkey, identity) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut state = VoteStateV4::new(
+            &vote_pubkey,
+            &VoteInit {
+                node_pubkey: identity,
+                authorized_voter: identity,
+                authorized_withdrawer: identity,
+                commission: 5,
+            },
+            &Default::default(),
+        );
+        state.inflation_rewards_commission_bps = 550;
+        let data = bincode::serialize(&VoteStateVersions::new_v4(state)).unwrap();
+        let account = owned_by(vote::id(), data);
+
+        let decoded = decode_account(&vote_pubkey, &account).unwrap();
+        assert_eq!(decoded.kind, "Vote Account");
+        let identity = identity.to_string();
+        assert_eq!(
+            field(&decoded, "Validator Identity"),
+            Some(identity.as_str())
+        );
+        assert_eq!(field(&decoded, "Commission"), Some("5.50%"));
+        assert_eq!(field(&decoded, "Root Slot"), Some("None"));
+    }
+
+    #[test]
+    fn test_decode_token_mint_and_account() {
+        use {
+            solana_program_pack::Pack,
+            spl_token_2022_interface::state::{Account as TokenAccount, AccountState, Mint},
+        };
+
+        let token_program = Pubkey::from_str_const(crate::constants::TOKEN_PROGRAM_ID);
+        let (mint_pubkey, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                supply: 1_500_000,
+                decimals: 6,
+                is_initialized: true,
+                ..Mint::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let decoded = decode_account(&mint_pubkey, &owned_by(token_program, data)).unwrap();
+        assert!(decoded.kind.starts_with("Mint"));
+        assert_eq!(field(&decoded, "Decimals"), Some("6"));
+        assert_eq!(field(&decoded, "Mint Authority"), Some("None"));
+
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                mint: mint_pubkey,
+                owner,
+                amount: 250,
+                state: AccountState::Initialized,
+                ..TokenAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let decoded =
+            decode_account(&Pubkey::new_unique(), &owned_by(token_program, data)).unwrap();
+        assert!(decoded.kind.starts_with("Token Account"));
+        let owner = owner.to_string();
+        assert_eq!(field(&decoded, "Owner"), Some(owner.as_str()));
+        assert_eq!(field(&decoded, "Amount (raw)"), Some("250"));
+    }
+
+    #[test]
+    fn test_decode_lookup_table() {
+        use solana_address_lookup_table_interface::state::{
+            LOOKUP_TABLE_META_SIZE, LookupTableMeta, ProgramState,
+        };
+
+        let authority = Pubkey::new_unique();
+        let mut data = bincode::serialize(&ProgramState::LookupTable(LookupTableMeta {
+            authority: Some(authority),
+            last_extended_slot: 99,
+            ..LookupTableMeta::default()
+        }))
+        .unwrap();
+        data.resize(LOOKUP_TABLE_META_SIZE, 0);
+        for _ in 0..3 {
+            data.extend(Pubkey::new_unique().to_bytes());
+        }
+
+        let decoded = decode_account(
+            &Pubkey::new_unique(),
+            &owned_by(address_lookup_table::id(), data),
+        )
+        .unwrap();
+        assert_eq!(decoded.kind, "Address Lookup Table");
+        assert_eq!(field(&decoded, "Status"), Some("Active"));
+        let authority = authority.to_string();
+        assert_eq!(field(&decoded, "Authority"), Some(authority.as_str()));
+        assert_eq!(field(&decoded, "Addresses"), Some("3"));
+        assert_eq!(field(&decoded, "Last Extended Slot"), Some("99"));
+    }
+
+    #[test]
+    fn test_decode_upgradeable_loader_accounts() {
+        let programdata_address = Pubkey::new_unique();
+        let program = bincode::serialize(&UpgradeableLoaderState::Program {
+            programdata_address,
+        })
+        .unwrap();
+        let decoded = decode_account(
+            &Pubkey::new_unique(),
+            &owned_by(bpf_loader_upgradeable::id(), program),
+        )
+        .unwrap();
+        assert_eq!(decoded.kind, "Program");
+        let programdata = programdata_address.to_string();
+        assert_eq!(
+            field(&decoded, "Program Data Account"),
+            Some(programdata.as_str())
+        );
+
+        let mut programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 12,
+            upgrade_authority_address: None,
+        })
+        .unwrap();
+        programdata.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+        programdata.extend([0x7f; 100]);
+        let decoded = decode_account(
+            &Pubkey::new_unique(),
+            &owned_by(bpf_loader_upgradeable::id(), programdata),
+        )
+        .unwrap();
+        assert_eq!(decoded.kind, "Program Data");
+        assert_eq!(field(&decoded, "Last Deployed Slot"), Some("12"));
+        assert_eq!(
+            field(&decoded, "Upgrade Authority"),
+            Some("None (immutable)")
+        );
+        assert_eq!(field(&decoded, "Program Length"), Some("100"));
+
+        let authority = Pubkey::new_unique();
+        let mut buffer = bincode::serialize(&UpgradeableLoaderState::Buffer {
+            authority_address: Some(authority),
+        })
+        .unwrap();
+        buffer.resize(UpgradeableLoaderState::size_of_buffer_metadata(), 0);
+        buffer.extend([1; 10]);
+        let decoded = decode_account(
+            &Pubkey::new_unique(),
+            &owned_by(bpf_loader_upgradeable::id(), buffer),
+        )
+        .unwrap();
+        assert_eq!(decoded.kind, "Program Buffer");
+        assert_eq!(field(&decoded, "Data Length"), Some("10"));
+    }
+
+    #[test]
+    fn test_unknown_owner_is_not_decoded() {
+        let account = Account {
+            lamports: 1,
+            data: vec![1, 2, 3],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        assert_eq!(decode_account(&Pubkey::new_unique(), &account), None);
+    }
+
+    #[test]
+    fn test_account_changes() {
+        let pubkey = Pubkey::new_unique();
+        let stake_account = |lamports: u64, staker: Pubkey| Account {
+            lamports,
+            data: bincode::serialize(&StakeStateV2::Initialized(Meta {
+                authorized: solana_stake_interface::state::Authorized {
+                    staker,
+                    withdrawer: staker,
+                },
+                ..Meta::default()
+            }))
+            .unwrap(),
+            owner: stake::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        let (old_staker, new_staker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let before = stake_account(1_000, old_staker);
+
+        assert!(account_changes(&pubkey, Some(&before), Some(&before)).is_empty());
+
+        let after = stake_account(1_500, old_staker);
+        let changes = account_changes(&pubkey, Some(&before), Some(&after));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "Balance");
+        assert!(changes[0].after.ends_with("(+500 lamports)"));
+
+        let after = stake_account(1_000, new_staker);
+        let changes = account_changes(&pubkey, Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![
+                AccountChange::new("Stake Authority", old_staker, new_staker),
+                AccountChange::new("Withdraw Authority", old_staker, new_staker),
+            ]
+        );
+
+        let changes = account_changes(&pubkey, Some(&before), None);
+        assert_eq!(changes[0].after, "closed");
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let dump = hex_dump(b"Scilla\x00\x01 hex dump test!");
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("00000000  53 63 69 6c 6c 61 00 01"));
+        assert!(lines[0].ends_with("|Scilla.. hex dum|"));
+        assert!(lines[1].starts_with("00000010  70 20 74 65 73 74 21"));
+    }
+}
@@ -0,0 +1,684 @@
+use {
+    crate::{
+        ScillaContext,
+        constants::{ACTIVE_STAKE_EPOCH_BOUND, MAX_TRANSACTION_SIZE},
+        misc::helpers::{SolAmount, bincode_deserialize, lamports_to_sol, read_csv},
+    },
+    anyhow::{Context, anyhow, bail},
+    serde::Deserialize,
+    solana_account::Account,
+    solana_instruction::Instruction,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    solana_stake_interface::{
+        instruction::{deactivate_stake, delegate_stake, withdraw},
+        program::id as stake_program_id,
+        stake_history::StakeHistory,
+        state::StakeStateV2,
+    },
+    solana_system_interface::instruction::transfer,
+    std::{collections::BTreeMap, fmt, fs, path::Path},
+};
+
+/// Columns describing an operation, shared by plan and results files
+pub const PLAN_COLUMNS: [&str; 5] = [
+    "kind",
+    "stake_account",
+    "vote_account",
+    "recipient",
+    "amount",
+];
+
+/// Extra columns appended to a results file
+pub const RESULT_COLUMNS: [&str; 3] = ["status", "signature", "error"];
+
+pub const STATUS_SUCCESS: &str = "success";
+pub const STATUS_FAILED: &str = "failed";
+
+/// Sent, but whether it landed is not known; checked before any retry
+pub const STATUS_UNKNOWN: &str = "unknown";
+
+/// One row of a plan file, keyed by column name
+#[derive(Debug, Clone)]
+pub struct PlanRecord {
+    pub row: usize,
+    pub values: BTreeMap<String, String>,
+}
+
+impl PlanRecord {
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.values
+            .get(column)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Rows recorded as successful in a previous run are not executed again
+    pub fn already_succeeded(&self) -> bool {
+        self.get("status") == Some(STATUS_SUCCESS)
+    }
+
+    /// Signature of a row whose previous send may or may not have landed
+    pub fn unknown_signature(&self) -> anyhow::Result<Option<Signature>> {
+        if self.get("status") != Some(STATUS_UNKNOWN) {
+            return Ok(None);
+        }
+        self.get("signature")
+            .ok_or_else(|| anyhow!("outcome is unknown and no signature was recorded"))?
+            .parse()
+            .map(Some)
+            .map_err(|_| anyhow!("`signature` is not a valid signature"))
+    }
+
+    /// Records the outcome of sending this row
+    pub fn set_result(&mut self, status: &str, signature: Option<&Signature>, error: &str) {
+        let signature = signature.map(Signature::to_string).unwrap_or_default();
+        for (column, value) in RESULT_COLUMNS.iter().zip([status, &signature, error]) {
+            self.values.insert(column.to_string(), value.to_string());
+        }
+    }
+
+    fn pubkey(&self, column: &str) -> anyhow::Result<Pubkey> {
+        self.get(column)
+            .ok_or_else(|| anyhow!("missing `{column}`"))?
+            .parse()
+            .map_err(|_| anyhow!("`{column}` is not a valid pubkey"))
+    }
+
+    fn optional_pubkey(&self, column: &str) -> anyhow::Result<Option<Pubkey>> {
+        self.get(column)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| anyhow!("`{column}` is not a valid pubkey"))
+            })
+            .transpose()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlPlan {
+    #[serde(default)]
+    operations: Vec<toml::Table>,
+}
+
+/// Loads a plan from a `.toml` file (an `[[operations]]` array) or a CSV file
+/// with a header row
+pub fn load_plan<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<PlanRecord>> {
+    let path = path.as_ref();
+
+    let records: Vec<PlanRecord> = if path.extension().is_some_and(|ext| ext == "toml") {
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let plan: TomlPlan =
+            toml::from_str(&data).with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        plan.operations
+            .into_iter()
+            .enumerate()
+            .map(|(idx, table)| PlanRecord {
+                row: idx + 1,
+                values: table
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let value = match value {
+                            toml::Value::String(s) => s,
+                            other => other.to_string(),
+                        };
+                        (key, value)
+                    })
+                    .collect(),
+            })
+            .collect()
+    } else {
+        let (headers, rows) = read_csv(path)?;
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(idx, row)| PlanRecord {
+                row: idx + 1,
+                values: headers.iter().cloned().zip(row).collect(),
+            })
+            .collect()
+    };
+
+    if records.is_empty() {
+        bail!("{} contains no operations", path.display());
+    }
+
+    Ok(records)
+}
+
+/// Kind of operation a plan row performs
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOperation {
+    Transfer {
+        recipient: Pubkey,
+        lamports: u64,
+    },
+    Delegate {
+        stake_account: Pubkey,
+        vote_account: Pubkey,
+    },
+    Deactivate {
+        stake_account: Pubkey,
+    },
+    Withdraw {
+        stake_account: Pubkey,
+        recipient: Option<Pubkey>,
+        /// `None` withdraws the full balance
+        lamports: Option<u64>,
+    },
+}
+
+impl fmt::Display for BatchOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchOperation::Transfer {
+                recipient,
+                lamports,
+            } => write!(
+                f,
+                "Transfer {} SOL to {recipient}",
+                lamports_to_sol(*lamports)
+            ),
+            BatchOperation::Delegate {
+                stake_account,
+                vote_account,
+            } => write!(f, "Delegate {stake_account} to {vote_account}"),
+            BatchOperation::Deactivate { stake_account } => {
+                write!(f, "Deactivate {stake_account}")
+            }
+            BatchOperation::Withdraw {
+                stake_account,
+                lamports,
+                ..
+            } => match lamports {
+                Some(lamports) => write!(
+                    f,
+                    "Withdraw {} SOL from {stake_account}",
+                    lamports_to_sol(*lamports)
+                ),
+                None => write!(f, "Withdraw all from {stake_account}"),
+            },
+        }
+    }
+}
+
+/// Parses a plan row without touching the network
+pub fn parse_operation(record: &PlanRecord) -> anyhow::Result<BatchOperation> {
+    let kind = record
+        .get("kind")
+        .ok_or_else(|| anyhow!("missing `kind`"))?;
+
+    let amount = || -> anyhow::Result<Option<u64>> {
+        match record.get("amount") {
+            None => Ok(None),
+            Some(amount) if amount.eq_ignore_ascii_case("all") => Ok(None),
+            Some(amount) => Ok(Some(amount.parse::<SolAmount>()?.to_lamports())),
+        }
+    };
+
+    let operation = match kind.to_ascii_lowercase().as_str() {
+        "transfer" => BatchOperation::Transfer {
+            recipient: record.pubkey("recipient")?,
+            lamports: amount()?.ok_or_else(|| anyhow!("transfers need an `amount`"))?,
+        },
+        "delegate" => BatchOperation::Delegate {
+            stake_account: record.pubkey("stake_account")?,
+            vote_account: record.pubkey("vote_account")?,
+        },
+        "deactivate" => BatchOperation::Deactivate {
+            stake_account: record.pubkey("stake_account")?,
+        },
+        "withdraw" => BatchOperation::Withdraw {
+            stake_account: record.pubkey("stake_account")?,
+            recipient: record.optional_pubkey("recipient")?,
+            lamports: amount()?,
+        },
+        other => {
+            bail!("unknown kind `{other}` (expected transfer, delegate, deactivate or withdraw)")
+        }
+    };
+
+    Ok(operation)
+}
+
+/// Checks an operation against on-chain state and builds its instruction.
+///
+/// All operations are authorized by the configured wallet, which must be the
+/// staker (delegate, deactivate) or withdrawer (withdraw) of the stake account.
+pub fn build_instruction(
+    operation: &BatchOperation,
+    authority: &Pubkey,
+    accounts: &BTreeMap<Pubkey, Account>,
+    current_epoch: u64,
+    stake_history: &StakeHistory,
+    new_rate_activation_epoch: Option<u64>,
+) -> anyhow::Result<Instruction> {
+    let stake_state = |stake_account: &Pubkey| -> anyhow::Result<(&Account, StakeStateV2)> {
+        let account = accounts
+            .get(stake_account)
+            .ok_or_else(|| anyhow!("stake account {stake_account} does not exist"))?;
+        if account.owner != stake_program_id() {
+            bail!("{stake_account} is not owned by the stake program");
+        }
+        let state = bincode_deserialize(&account.data, "stake account data")?;
+        Ok((account, state))
+    };
+
+    let instruction = match operation {
+        BatchOperation::Transfer {
+            recipient,
+            lamports,
+        } => transfer(authority, recipient, *lamports),
+        BatchOperation::Delegate {
+            stake_account,
+            vote_account,
+        } => {
+            let (_, state) = stake_state(stake_account)?;
+            let meta = match &state {
+                StakeStateV2::Initialized(meta) => meta,
+                StakeStateV2::Stake(meta, stake, _) => {
+                    if stake.delegation.deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND {
+                        bail!(
+                            "{stake_account} is already delegated to {}",
+                            stake.delegation.voter_pubkey
+                        );
+                    }
+                    // Stake still cooling down can only have its deactivation
+                    // rescinded, to the same vote account and within the
+                    // deactivation epoch; anything else is TooSoonToRedelegate
+                    let status = stake.delegation.stake_activating_and_deactivating(
+                        current_epoch,
+                        stake_history,
+                        new_rate_activation_epoch,
+                    );
+                    let fully_deactivated = status.effective == 0 && status.deactivating == 0;
+                    let rescinds_deactivation = *vote_account == stake.delegation.voter_pubkey
+                        && current_epoch == stake.delegation.deactivation_epoch;
+                    if !fully_deactivated && !rescinds_deactivation {
+                        bail!(
+                            "{stake_account} is still cooling down from {}; it can only be \
+                             delegated elsewhere once fully deactivated",
+                            stake.delegation.voter_pubkey
+                        );
+                    }
+                    meta
+                }
+                _ => bail!("{stake_account} is not initialized"),
+            };
+            if meta.authorized.staker != *authority {
+                bail!(
+                    "wallet is not the staker of {stake_account} (staker is {})",
+                    meta.authorized.staker
+                );
+            }
+            if accounts
+                .get(vote_account)
+                .is_none_or(|account| account.owner != solana_sdk_ids::vote::id())
+            {
+                bail!("{vote_account} is not a vote account");
+            }
+
+            delegate_stake(stake_account, authority, vote_account)
+        }
+        BatchOperation::Deactivate { stake_account } => {
+            let (_, state) = stake_state(stake_account)?;
+            let StakeStateV2::Stake(meta, stake, _) = state else {
+                bail!("{stake_account} is not delegated");
+            };
+            if stake.delegation.deactivation_epoch != ACTIVE_STAKE_EPOCH_BOUND {
+                bail!(
+                    "{stake_account} is already deactivating at epoch {}",
+                    stake.delegation.deactivation_epoch
+                );
+            }
+            if meta.authorized.staker != *authority {
+                bail!(
+                    "wallet is not the staker of {stake_account} (staker is {})",
+                    meta.authorized.staker
+                );
+            }
+
+            deactivate_stake(stake_account, authority)
+        }
+        BatchOperation::Withdraw {
+            stake_account,
+            recipient,
+            lamports,
+        } => {
+            let (account, state) = stake_state(stake_account)?;
+            // Lamports still staked can't be withdrawn; the stake program
+            // only frees them as the cooldown progresses epoch by epoch
+            let (meta, staked) = match &state {
+                StakeStateV2::Initialized(meta) => (meta, 0),
+                StakeStateV2::Stake(meta, stake, _) => {
+                    if stake.delegation.deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND {
+                        bail!("{stake_account} is still active; deactivate it first");
+                    }
+                    let status = stake.delegation.stake_activating_and_deactivating(
+                        current_epoch,
+                        stake_history,
+                        new_rate_activation_epoch,
+                    );
+                    // Deactivating stake is part of the effective amount
+                    (meta, status.effective)
+                }
+                _ => bail!("{stake_account} is not initialized"),
+            };
+            if meta.authorized.withdrawer != *authority {
+                bail!(
+                    "wallet is not the withdrawer of {stake_account} (withdrawer is {})",
+                    meta.authorized.withdrawer
+                );
+            }
+
+            let lamports = match lamports {
+                Some(lamports) => *lamports,
+                None if staked > 0 => bail!(
+                    "{stake_account} is still cooling down ({} SOL not yet deactivated); it can't \
+                     be emptied yet",
+                    lamports_to_sol(staked)
+                ),
+                None => account.lamports,
+            };
+            if lamports > account.lamports {
+                bail!(
+                    "{stake_account} holds only {} SOL",
+                    lamports_to_sol(account.lamports)
+                );
+            }
+            let remaining = account.lamports - lamports;
+            if staked > 0 && remaining < meta.rent_exempt_reserve + staked {
+                bail!(
+                    "{stake_account} still has {} SOL cooling down; withdraw at most {} SOL",
+                    lamports_to_sol(staked),
+                    lamports_to_sol(
+                        account
+                            .lamports
+                            .saturating_sub(meta.rent_exempt_reserve + staked)
+                    )
+                );
+            }
+            // A partial withdraw must leave the account rent exempt
+            if remaining > 0 && remaining < meta.rent_exempt_reserve {
+                bail!(
+                    "withdrawing {} SOL would leave {stake_account} below its rent-exempt reserve \
+                     of {} SOL; withdraw at most {} SOL or the full balance",
+                    lamports_to_sol(lamports),
+                    lamports_to_sol(meta.rent_exempt_reserve),
+                    lamports_to_sol(account.lamports.saturating_sub(meta.rent_exempt_reserve))
+                );
+            }
+
+            withdraw(
+                stake_account,
+                authority,
+                &recipient.unwrap_or(*authority),
+                lamports,
+                None,
+            )
+        }
+    };
+
+    Ok(instruction)
+}
+
+/// Accounts that must be fetched to validate the operations
+pub fn accounts_to_fetch(operations: &[&BatchOperation]) -> Vec<Pubkey> {
+    let mut pubkeys = Vec::new();
+    for operation in operations {
+        match operation {
+            BatchOperation::Transfer { .. } => {}
+            BatchOperation::Delegate {
+                stake_account,
+                vote_account,
+            } => pubkeys.extend([*stake_account, *vote_account]),
+            BatchOperation::Deactivate { stake_account }
+            | BatchOperation::Withdraw { stake_account, .. } => pubkeys.push(*stake_account),
+        }
+    }
+    pubkeys.sort();
+    pubkeys.dedup();
+    pubkeys
+}
+
+/// Fetches the given accounts, skipping those that don't exist
+pub async fn fetch_accounts(
+    ctx: &ScillaContext,
+    pubkeys: &[Pubkey],
+) -> anyhow::Result<BTreeMap<Pubkey, Account>> {
+    // getMultipleAccounts accepts at most 100 keys per request
+    let mut accounts = BTreeMap::new();
+    for chunk in pubkeys.chunks(100) {
+        let fetched = ctx.rpc().get_multiple_accounts(chunk).await?;
+        accounts.extend(
+            chunk
+                .iter()
+                .zip(fetched)
+                .filter_map(|(pubkey, account)| Some((*pubkey, account?))),
+        );
+    }
+    Ok(accounts)
+}
+
+/// Serialized size of a legacy transaction carrying `instructions`
+pub fn transaction_size(payer: &Pubkey, instructions: &[&Instruction]) -> usize {
+    let instructions: Vec<Instruction> = instructions.iter().map(|ix| (*ix).clone()).collect();
+    let message = Message::new(&instructions, Some(payer));
+    let num_signatures = message.header.num_required_signatures as usize;
+
+    // Compact-u16 signature count (one byte below 128) plus the signatures
+    1 + num_signatures * 64 + bincode::serialized_size(&message).unwrap_or(u64::MAX) as usize
+}
+
+/// Groups instructions, in order, into as few transactions as fit the
+/// packet size. Returns the indexes of the instructions in each group.
+pub fn pack_instructions(payer: &Pubkey, instructions: &[Instruction]) -> Vec<Vec<usize>> {
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+
+    for idx in 0..instructions.len() {
+        let mut candidate: Vec<&Instruction> = current.iter().map(|i| &instructions[*i]).collect();
+        candidate.push(&instructions[idx]);
+
+        if !current.is_empty() && transaction_size(payer, &candidate) > MAX_TRANSACTION_SIZE {
+            batches.push(std::mem::take(&mut current));
+        }
+        current.push(idx);
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(values: &[(&str, &str)]) -> PlanRecord {
+        PlanRecord {
+            row: 1,
+            values: values
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_operation() -> anyhow::Result<()> {
+        let stake = Pubkey::new_unique();
+
+        let operation = parse_operation(&record(&[
+            ("kind", "Withdraw"),
+            ("stake_account", &stake.to_string()),
+            ("amount", "all"),
+        ]))?;
+        assert_eq!(
+            operation,
+            BatchOperation::Withdraw {
+                stake_account: stake,
+                recipient: None,
+                lamports: None,
+            }
+        );
+
+        assert!(parse_operation(&record(&[("kind", "transfer")])).is_err());
+        assert!(parse_operation(&record(&[("kind", "burn")])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_toml_plan() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("plan.toml");
+        fs::write(
+            &path,
+            format!(
+                "[[operations]]\nkind = \"transfer\"\nrecipient = \"{}\"\namount = 1.5\n",
+                Pubkey::new_unique()
+            ),
+        )?;
+
+        let records = load_plan(&path)?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("amount"), Some("1.5"));
+        assert!(matches!(
+            parse_operation(&records[0])?,
+            BatchOperation::Transfer {
+                lamports: 1_500_000_000,
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_withdraw_checks() -> anyhow::Result<()> {
+        use solana_stake_interface::{
+            stake_flags::StakeFlags,
+            stake_history::StakeHistoryEntry,
+            state::{Authorized, Delegation, Meta, Stake},
+        };
+
+        let wallet = Pubkey::new_unique();
+        let stake_account = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let reserve = 2_282_880;
+        let meta = Meta {
+            rent_exempt_reserve: reserve,
+            authorized: Authorized::auto(&wallet),
+            ..Meta::default()
+        };
+        let delegated = |activation_epoch, deactivation_epoch| {
+            let state = StakeStateV2::Stake(
+                meta,
+                Stake {
+                    delegation: Delegation {
+                        voter_pubkey: voter,
+                        stake: 5_000_000_000,
+                        activation_epoch,
+                        deactivation_epoch,
+                        ..Delegation::default()
+                    },
+                    credits_observed: 0,
+                },
+                StakeFlags::empty(),
+            );
+            let account = Account {
+                lamports: 5_000_000_000 + reserve,
+                data: bincode::serialize(&state).unwrap(),
+                owner: stake_program_id(),
+                ..Account::default()
+            };
+            let vote_account = Account {
+                owner: solana_sdk_ids::vote::id(),
+                ..Account::default()
+            };
+            BTreeMap::from([(stake_account, account), (voter, vote_account)])
+        };
+        let withdraw = |lamports| BatchOperation::Withdraw {
+            stake_account,
+            recipient: None,
+            lamports,
+        };
+
+        let no_history = StakeHistory::default();
+        let build = |operation: &BatchOperation, accounts, epoch, history| {
+            build_instruction(operation, &wallet, accounts, epoch, history, Some(0))
+        };
+
+        // Deactivated in its activation epoch: never effective, no cooldown
+        let accounts = delegated(10, 10);
+        assert!(build(&withdraw(None), &accounts, 10, &no_history).is_ok());
+        // Activated earlier and still cooling down
+        let accounts = delegated(5, 10);
+        assert!(build(&withdraw(None), &accounts, 10, &no_history).is_err());
+        assert!(build(&withdraw(None), &accounts, 11, &no_history).is_ok());
+
+        // Partial withdraws must leave the rent-exempt reserve behind
+        assert!(build(&withdraw(Some(5_000_000_000)), &accounts, 11, &no_history).is_ok());
+        assert!(build(&withdraw(Some(5_000_000_001)), &accounts, 11, &no_history).is_err());
+
+        // Cooling down against 20 SOL of cluster stake at 9% per epoch frees
+        // only 1.8 SOL by the next epoch
+        let mut history = StakeHistory::default();
+        history.add(
+            10,
+            StakeHistoryEntry {
+                effective: 20_000_000_000,
+                activating: 0,
+                deactivating: 5_000_000_000,
+            },
+        );
+        assert!(build(&withdraw(None), &accounts, 11, &history).is_err());
+        assert!(build(&withdraw(Some(1_800_000_000)), &accounts, 11, &history).is_ok());
+        assert!(build(&withdraw(Some(1_800_000_001)), &accounts, 11, &history).is_err());
+
+        // While cooling down it can't move to another vote account, and only
+        // the deactivation epoch allows going back to the same one
+        let other_voter = Pubkey::new_unique();
+        let mut accounts = accounts.clone();
+        accounts.insert(
+            other_voter,
+            Account {
+                owner: solana_sdk_ids::vote::id(),
+                ..Account::default()
+            },
+        );
+        let delegate = |vote_account| BatchOperation::Delegate {
+            stake_account,
+            vote_account,
+        };
+        assert!(build(&delegate(other_voter), &accounts, 11, &history).is_err());
+        assert!(build(&delegate(voter), &accounts, 11, &history).is_err());
+        assert!(build(&delegate(voter), &accounts, 10, &history).is_ok());
+        assert!(build(&delegate(other_voter), &accounts, 11, &no_history).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pack_instructions_respects_packet_size() {
+        let payer = Pubkey::new_unique();
+        let instructions: Vec<Instruction> = (0..60)
+            .map(|_| transfer(&payer, &Pubkey::new_unique(), 1))
+            .collect();
+
+        let batches = pack_instructions(&payer, &instructions);
+
+        assert!(batches.len() > 1);
+        assert_eq!(batches.iter().map(Vec::len).sum::<usize>(), 60);
+        for batch in &batches {
+            let ixs: Vec<&Instruction> = batch.iter().map(|i| &instructions[*i]).collect();
+            assert!(transaction_size(&payer, &ixs) <= MAX_TRANSACTION_SIZE);
+        }
+    }
+}
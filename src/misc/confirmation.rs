@@ -0,0 +1,278 @@
+use {
+    crate::{
+        ScillaContext, constants::CONFIRMATION_POLL_INTERVAL_MS,
+        misc::instructions::advance_nonce_account,
+    },
+    solana_commitment_config::{CommitmentConfig, CommitmentLevel},
+    solana_rpc_client_api::config::RpcSendTransactionConfig,
+    solana_signature::Signature,
+    solana_transaction::versioned::VersionedTransaction,
+    solana_transaction_error::TransactionError,
+    solana_transaction_status::TransactionConfirmationStatus,
+    std::time::{Duration, Instant},
+};
+
+/// How long to follow a transaction and whether to keep re-sending it
+#[derive(Debug, Clone)]
+pub struct TrackOptions {
+    pub target: TransactionConfirmationStatus,
+    pub resend_interval: Option<Duration>,
+}
+
+impl TrackOptions {
+    /// Follows the transaction up to the commitment the RPC client uses
+    pub fn for_commitment(commitment: CommitmentConfig) -> Self {
+        let target = match commitment.commitment {
+            CommitmentLevel::Processed => TransactionConfirmationStatus::Processed,
+            CommitmentLevel::Confirmed => TransactionConfirmationStatus::Confirmed,
+            CommitmentLevel::Finalized => TransactionConfirmationStatus::Finalized,
+        };
+
+        Self {
+            target,
+            resend_interval: None,
+        }
+    }
+}
+
+/// A commitment level the transaction reached, and when
+#[derive(Debug, Clone)]
+pub struct StageReached {
+    pub status: TransactionConfirmationStatus,
+    pub slot: u64,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub enum TrackOutcome {
+    /// Reached the target commitment
+    Landed,
+    /// Landed but the transaction itself failed
+    Failed(TransactionError),
+    /// The blockhash expired before the transaction was seen; it can no longer
+    /// land
+    Expired,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfirmationReport {
+    pub signature: Signature,
+    pub stages: Vec<StageReached>,
+    pub outcome: TrackOutcome,
+    pub resends: usize,
+    pub elapsed: Duration,
+}
+
+/// What to do after a poll that didn't find the transaction
+#[derive(Debug, PartialEq, Eq)]
+enum UnseenStep {
+    /// The blockhash expired; unless a last lookup finds it, it can't land
+    CheckExpired,
+    Resend,
+    Wait,
+}
+
+/// Durable-nonce transactions don't expire with their blockhash
+fn can_expire(tx: &VersionedTransaction) -> bool {
+    advance_nonce_account(&tx.message).is_none()
+}
+
+fn next_unseen_step(
+    blockhash_expired: bool,
+    since_last_send: Duration,
+    resend_interval: Option<Duration>,
+) -> UnseenStep {
+    if blockhash_expired {
+        UnseenStep::CheckExpired
+    } else if resend_interval.is_some_and(|interval| since_last_send >= interval) {
+        UnseenStep::Resend
+    } else {
+        UnseenStep::Wait
+    }
+}
+
+fn rank(status: &TransactionConfirmationStatus) -> u8 {
+    match status {
+        TransactionConfirmationStatus::Processed => 0,
+        TransactionConfirmationStatus::Confirmed => 1,
+        TransactionConfirmationStatus::Finalized => 2,
+    }
+}
+
+/// Polls an already sent transaction until it reaches `options.target`, fails,
+/// or its blockhash expires, calling `on_stage` each time it moves up a
+/// commitment level.
+///
+/// Durable-nonce transactions don't expire with their blockhash, so expiry is
+/// only detected for regular transactions.
+pub async fn track_confirmation(
+    ctx: &ScillaContext,
+    tx: &VersionedTransaction,
+    options: &TrackOptions,
+    mut on_stage: impl FnMut(&StageReached),
+) -> anyhow::Result<ConfirmationReport> {
+    let signature = *tx
+        .signatures
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Transaction has no signatures"))?;
+    let blockhash = *tx.message.recent_blockhash();
+    let can_expire = can_expire(tx);
+
+    let start = Instant::now();
+    let mut last_send = start;
+    let mut resends = 0;
+    let mut stages: Vec<StageReached> = Vec::new();
+
+    let outcome = loop {
+        let status = ctx
+            .rpc()
+            .get_signature_statuses(&[signature])
+            .await?
+            .value
+            .into_iter()
+            .next()
+            .flatten();
+
+        match status {
+            Some(status) => {
+                if let Some(err) = status.err {
+                    break TrackOutcome::Failed(err);
+                }
+
+                // A poll can skip a level, e.g. see a transaction first as
+                // confirmed; the skipped levels are reported at the same slot
+                let reached = status.confirmation_status();
+                for level in [
+                    TransactionConfirmationStatus::Processed,
+                    TransactionConfirmationStatus::Confirmed,
+                    TransactionConfirmationStatus::Finalized,
+                ] {
+                    if rank(&level) > rank(&reached)
+                        || stages.iter().any(|stage| stage.status == level)
+                    {
+                        continue;
+                    }
+                    let stage = StageReached {
+                        status: level,
+                        slot: status.slot,
+                        elapsed: start.elapsed(),
+                    };
+                    on_stage(&stage);
+                    stages.push(stage);
+                }
+
+                if rank(&reached) >= rank(&options.target) {
+                    break TrackOutcome::Landed;
+                }
+            }
+            None => {
+                let blockhash_expired = can_expire
+                    && !ctx
+                        .rpc()
+                        .is_blockhash_valid(&blockhash, CommitmentConfig::processed())
+                        .await?;
+
+                match next_unseen_step(
+                    blockhash_expired,
+                    last_send.elapsed(),
+                    options.resend_interval,
+                ) {
+                    UnseenStep::CheckExpired => {
+                        // The transaction may have landed between the two calls
+                        let landed = ctx
+                            .rpc()
+                            .get_signature_statuses(&[signature])
+                            .await?
+                            .value
+                            .first()
+                            .is_some_and(Option::is_some);
+                        if !landed {
+                            break TrackOutcome::Expired;
+                        }
+                        continue;
+                    }
+                    UnseenStep::Resend => {
+                        ctx.rpc()
+                            .send_transaction_with_config(
+                                tx,
+                                RpcSendTransactionConfig {
+                                    skip_preflight: true,
+                                    ..RpcSendTransactionConfig::default()
+                                },
+                            )
+                            .await?;
+                        resends += 1;
+                        last_send = Instant::now();
+                    }
+                    UnseenStep::Wait => {}
+                }
+            }
+        }
+
+        tokio::time::sleep(Duration::from_millis(CONFIRMATION_POLL_INTERVAL_MS)).await;
+    };
+
+    Ok(ConfirmationReport {
+        signature,
+        stages,
+        outcome,
+        resends,
+        elapsed: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_message::{Message, VersionedMessage},
+        solana_pubkey::Pubkey,
+        solana_system_interface::instruction::{advance_nonce_account, transfer},
+    };
+
+    fn unsigned_tx(
+        payer: &Pubkey,
+        instructions: &[solana_instruction::Instruction],
+    ) -> VersionedTransaction {
+        VersionedTransaction {
+            signatures: vec![],
+            message: VersionedMessage::Legacy(Message::new(instructions, Some(payer))),
+        }
+    }
+
+    #[test]
+    fn test_durable_nonce_transactions_never_expire() {
+        let payer = Pubkey::new_unique();
+        let nonce = Pubkey::new_unique();
+        let pay = transfer(&payer, &Pubkey::new_unique(), 1);
+
+        assert!(can_expire(&unsigned_tx(&payer, std::slice::from_ref(&pay))));
+        assert!(!can_expire(&unsigned_tx(
+            &payer,
+            &[advance_nonce_account(&nonce, &payer), pay]
+        )));
+    }
+
+    #[test]
+    fn test_next_unseen_step() {
+        let interval = Some(Duration::from_secs(2));
+
+        // An expired blockhash wins over a due resend
+        assert_eq!(
+            next_unseen_step(true, Duration::from_secs(5), interval),
+            UnseenStep::CheckExpired
+        );
+        assert_eq!(
+            next_unseen_step(false, Duration::from_secs(2), interval),
+            UnseenStep::Resend
+        );
+        assert_eq!(
+            next_unseen_step(false, Duration::from_secs(1), interval),
+            UnseenStep::Wait
+        );
+        assert_eq!(
+            next_unseen_step(false, Duration::from_secs(60), None),
+            UnseenStep::Wait
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use {
+    crate::constants::HEALTH_CHECK_TIMEOUT_SECS,
+    solana_commitment_config::CommitmentConfig,
+    solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    std::time::{Duration, Instant},
+};
+
+/// What one RPC endpoint reported during a health check. Failed calls keep
+/// their error message so the comparison can show why a field is missing.
+#[derive(Debug, Clone)]
+pub struct EndpointReport {
+    pub url: String,
+    /// Round trip of the `getHealth` call
+    pub latency: Duration,
+    pub health: Result<(), String>,
+    pub version: Result<(String, Option<u32>), String>,
+    pub processed_slot: Result<u64, String>,
+    pub confirmed_slot: Result<u64, String>,
+    pub finalized_slot: Result<u64, String>,
+    pub first_available_block: Result<u64, String>,
+    pub minimum_ledger_slot: Result<u64, String>,
+}
+
+impl EndpointReport {
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_ok() && self.processed_slot.is_ok()
+    }
+
+    /// Slots behind the most advanced endpoint, `None` when the slot is unknown
+    pub fn slot_lag(&self, highest_slot: u64) -> Option<u64> {
+        self.processed_slot
+            .as_ref()
+            .ok()
+            .map(|slot| highest_slot.saturating_sub(*slot))
+    }
+}
+
+/// Queries everything the health check shows from a single endpoint
+pub async fn probe_endpoint(url: &str, commitment: CommitmentConfig) -> EndpointReport {
+    let rpc = RpcClient::new_with_timeout_and_commitment(
+        url.to_string(),
+        Duration::from_secs(HEALTH_CHECK_TIMEOUT_SECS),
+        commitment,
+    );
+
+    let started = Instant::now();
+    let health = rpc.get_health().await.map_err(|e| e.to_string());
+    let latency = started.elapsed();
+
+    let (
+        version,
+        processed_slot,
+        confirmed_slot,
+        finalized_slot,
+        first_available_block,
+        minimum_ledger_slot,
+    ) = tokio::join!(
+        async {
+            rpc.get_version()
+                .await
+                .map(|version| (version.solana_core, version.feature_set))
+                .map_err(|e| e.to_string())
+        },
+        async {
+            rpc.get_slot_with_commitment(CommitmentConfig::processed())
+                .await
+                .map_err(|e| e.to_string())
+        },
+        async {
+            rpc.get_slot_with_commitment(CommitmentConfig::confirmed())
+                .await
+                .map_err(|e| e.to_string())
+        },
+        async {
+            rpc.get_slot_with_commitment(CommitmentConfig::finalized())
+                .await
+                .map_err(|e| e.to_string())
+        },
+        async {
+            rpc.get_first_available_block()
+                .await
+                .map_err(|e| e.to_string())
+        },
+        async { rpc.minimum_ledger_slot().await.map_err(|e| e.to_string()) },
+    );
+
+    EndpointReport {
+        url: url.to_string(),
+        latency,
+        health,
+        version,
+        processed_slot,
+        confirmed_slot,
+        finalized_slot,
+        first_available_block,
+        minimum_ledger_slot,
+    }
+}
+
+/// Index of the endpoint to use: healthy, then least behind, then fastest
+pub fn recommend_endpoint(reports: &[EndpointReport]) -> Option<usize> {
+    let highest_slot = reports
+        .iter()
+        .filter_map(|report| report.processed_slot.as_ref().ok().copied())
+        .max()?;
+
+    reports
+        .iter()
+        .enumerate()
+        .filter(|(_, report)| report.is_healthy())
+        .min_by_key(|(_, report)| (report.slot_lag(highest_slot), report.latency))
+        .map(|(idx, _)| idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(url: &str, healthy: bool, slot: u64, latency_ms: u64) -> EndpointReport {
+        EndpointReport {
+            url: url.to_string(),
+            latency: Duration::from_millis(latency_ms),
+            health: if healthy {
+                Ok(())
+            } else {
+                Err("Node is behind".to_string())
+            },
+            version: Ok(("3.0.0".to_string(), Some(1))),
+            processed_slot: Ok(slot),
+            confirmed_slot: Ok(slot),
+            finalized_slot: Ok(slot),
+            first_available_block: Ok(0),
+            minimum_ledger_slot: Ok(0),
+        }
+    }
+
+    #[test]
+    fn test_recommend_endpoint_prefers_least_lag_then_latency() {
+        let reports = vec![
+            report("lagging", true, 90, 10),
+            report("unhealthy", false, 100, 5),
+            report("slow", true, 100, 300),
+            report("fast", true, 100, 50),
+        ];
+        assert_eq!(recommend_endpoint(&reports), Some(3));
+        assert_eq!(reports[0].slot_lag(100), Some(10));
+
+        let mut unreachable = report("down", true, 0, 0);
+        unreachable.processed_slot = Err("timed out".to_string());
+        assert_eq!(recommend_endpoint(&[unreachable]), None);
+    }
+}
@@ -1,16 +1,26 @@
 use {
-    crate::{ScillaContext, constants::LAMPORTS_PER_SOL},
+    crate::{
+        ScillaContext,
+        constants::{
+            DEFAULT_MS_PER_SLOT, LAMPORTS_PER_SOL, PERFORMANCE_SAMPLE_LIMIT, SECONDS_PER_YEAR,
+        },
+        misc::confirmation::{TrackOptions, TrackOutcome, track_confirmation},
+    },
     anyhow::{Context, anyhow, bail},
     base64::Engine,
     bincode::Options,
+    chrono::{DateTime, Utc},
     solana_account::Account,
+    solana_address_lookup_table_interface::state::AddressLookupTable,
     solana_epoch_info::EpochInfo,
     solana_instruction::Instruction,
     solana_keypair::{EncodableKey, Keypair, Signature, Signer},
-    solana_message::Message,
+    solana_message::{AddressLookupTableAccount, Hash, Message, VersionedMessage, v0},
     solana_pubkey::Pubkey,
-    solana_transaction::Transaction,
-    std::{path::Path, str::FromStr},
+    solana_rpc_client_api::response::RpcPerfSample,
+    solana_sdk_ids::address_lookup_table,
+    solana_transaction::versioned::VersionedTransaction,
+    std::{collections::BTreeMap, fs, path::Path, str::FromStr},
     tokio::try_join,
 };
 
@@ -80,6 +90,99 @@ impl FromStr for SolAmount {
     }
 }
 
+/// One or more pubkeys separated by commas or whitespace
+#[derive(Debug, Clone)]
+pub struct PubkeyList(Vec<Pubkey>);
+
+impl PubkeyList {
+    pub fn pubkeys(&self) -> &[Pubkey] {
+        &self.0
+    }
+}
+
+impl FromStr for PubkeyList {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pubkeys = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| Pubkey::from_str(part).map_err(|_| anyhow!("Invalid pubkey: {part}")))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if pubkeys.is_empty() {
+            bail!("Enter at least one pubkey");
+        }
+        Ok(PubkeyList(pubkeys))
+    }
+}
+
+/// A future point on the cluster timeline, entered as `epoch N` or `slot N`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochTarget {
+    Epoch(u64),
+    Slot(u64),
+}
+
+impl FromStr for EpochTarget {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let (Some(kind), Some(value), None) = (parts.next(), parts.next(), parts.next()) else {
+            bail!("Enter `epoch <number>` or `slot <number>`");
+        };
+        let value =
+            trim_and_parse::<u64>(value, kind)?.ok_or_else(|| anyhow!("Missing {kind} number"))?;
+
+        match kind.to_lowercase().as_str() {
+            "epoch" => Ok(EpochTarget::Epoch(value)),
+            "slot" => Ok(EpochTarget::Slot(value)),
+            other => bail!("Unknown target `{other}`, expected `epoch` or `slot`"),
+        }
+    }
+}
+
+/// One or more http(s) URLs separated by commas or whitespace
+#[derive(Debug, Clone)]
+pub struct UrlList(Vec<String>);
+
+impl UrlList {
+    pub fn urls(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl FromStr for UrlList {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let urls = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                if part.starts_with("http://") || part.starts_with("https://") {
+                    Ok(part.to_string())
+                } else {
+                    Err(anyhow!(
+                        "Invalid URL: {part}. Must start with http:// or https://"
+                    ))
+                }
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if urls.is_empty() {
+            bail!("Enter at least one URL");
+        }
+        Ok(UrlList(urls))
+    }
+}
+
+/// Formats a commission held in basis points, keeping fractional percents
+pub fn format_commission_bps(bps: u16) -> String {
+    format!("{:.2}%", bps as f64 / 100.0)
+}
+
 pub fn sol_to_lamports(sol: f64) -> u64 {
     (sol * LAMPORTS_PER_SOL as f64) as u64
 }
@@ -88,25 +191,333 @@ pub fn lamports_to_sol(lamports: u64) -> f64 {
     lamports as f64 / LAMPORTS_PER_SOL as f64
 }
 
+/// Number of epochs in a year assuming the nominal slot duration
+pub fn epochs_per_year(slots_per_epoch: u64) -> f64 {
+    let epoch_secs = slots_per_epoch as f64 * DEFAULT_MS_PER_SLOT as f64 / 1000.0;
+    SECONDS_PER_YEAR / epoch_secs
+}
+
+/// Compounds a single-epoch reward rate into an annual percentage yield
+pub fn annualized_apy(epoch_rate: f64, epochs_per_year: f64) -> f64 {
+    ((1.0 + epoch_rate).powf(epochs_per_year) - 1.0) * 100.0
+}
+
+/// Writes rows as CSV, quoting any field that contains a delimiter
+pub fn write_csv<P: AsRef<Path>>(
+    path: P,
+    headers: &[&str],
+    rows: &[Vec<String>],
+) -> anyhow::Result<()> {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    let mut out = headers
+        .iter()
+        .map(|h| escape(h))
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+    for row in rows {
+        out.push_str(&row.iter().map(|f| escape(f)).collect::<Vec<_>>().join(","));
+        out.push('\n');
+    }
+
+    let path = path.as_ref();
+    fs::write(path, out).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Reads a CSV file written by [`write_csv`] (or any simple CSV), returning
+/// the header followed by the rows
+pub fn read_csv<P: AsRef<Path>>(path: P) -> anyhow::Result<(Vec<String>, Vec<Vec<String>>)> {
+    let path = path.as_ref();
+    let data =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records.retain(|record| record.iter().any(|field| !field.trim().is_empty()));
+
+    let mut records = records.into_iter();
+    let headers = records
+        .next()
+        .ok_or_else(|| anyhow!("{} is empty", path.display()))?
+        .into_iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+
+    Ok((headers, records.collect()))
+}
+
+/// Smallest number of validators whose combined stake exceeds `fraction` of
+/// the total. `stakes` must be sorted in descending order.
+pub fn validators_to_exceed(stakes: &[u64], fraction: f64) -> usize {
+    let total: u128 = stakes.iter().map(|stake| *stake as u128).sum();
+    let threshold = total as f64 * fraction;
+
+    let mut cumulative: u128 = 0;
+    for (idx, stake) in stakes.iter().enumerate() {
+        cumulative += *stake as u128;
+        if cumulative as f64 > threshold {
+            return idx + 1;
+        }
+    }
+    stakes.len()
+}
+
+/// Nodes and stake running one software version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionStake {
+    pub version: String,
+    pub nodes: usize,
+    pub stake: u64,
+}
+
+/// Groups `(version, stake)` per node by version, most staked version first
+pub fn stake_by_version<'a>(nodes: impl IntoIterator<Item = (&'a str, u64)>) -> Vec<VersionStake> {
+    let mut by_version: BTreeMap<&str, (usize, u64)> = BTreeMap::new();
+    for (version, stake) in nodes {
+        let entry = by_version.entry(version).or_default();
+        entry.0 += 1;
+        entry.1 += stake;
+    }
+
+    let mut distribution: Vec<VersionStake> = by_version
+        .into_iter()
+        .map(|(version, (nodes, stake))| VersionStake {
+            version: version.to_string(),
+            nodes,
+            stake,
+        })
+        .collect();
+    distribution.sort_by(|a, b| b.stake.cmp(&a.stake).then(b.nodes.cmp(&a.nodes)));
+    distribution
+}
+
+/// Average slot duration in milliseconds across performance samples
+pub fn average_slot_time_ms(samples: &[RpcPerfSample]) -> Option<f64> {
+    let (slots, secs) = samples.iter().fold((0u64, 0u64), |(slots, secs), sample| {
+        (
+            slots + sample.num_slots,
+            secs + sample.sample_period_secs as u64,
+        )
+    });
+    (slots > 0).then(|| secs as f64 * 1000.0 / slots as f64)
+}
+
+/// Average transactions per second over `samples`, and the non-vote share
+/// of it when the node reports one
+pub fn transactions_per_second(samples: &[RpcPerfSample]) -> Option<(f64, Option<f64>)> {
+    let secs: u64 = samples
+        .iter()
+        .map(|sample| sample.sample_period_secs as u64)
+        .sum();
+    if secs == 0 {
+        return None;
+    }
+    let total: u64 = samples.iter().map(|sample| sample.num_transactions).sum();
+    let non_vote: Option<u64> = samples
+        .iter()
+        .map(|sample| sample.num_non_vote_transactions)
+        .sum();
+
+    Some((
+        total as f64 / secs as f64,
+        non_vote.map(|non_vote| non_vote as f64 / secs as f64),
+    ))
+}
+
+/// Measures the recent slot duration, falling back to the nominal 400ms when
+/// the node has no performance samples
+pub async fn fetch_average_slot_time_ms(ctx: &ScillaContext) -> anyhow::Result<f64> {
+    let samples = ctx
+        .rpc()
+        .get_recent_performance_samples(Some(PERFORMANCE_SAMPLE_LIMIT))
+        .await?;
+    Ok(average_slot_time_ms(&samples).unwrap_or(DEFAULT_MS_PER_SLOT as f64))
+}
+
+/// Projects when `target_slot` will be reached from `current_slot`
+pub fn estimate_slot_datetime(
+    current_slot: u64,
+    target_slot: u64,
+    ms_per_slot: f64,
+) -> DateTime<Utc> {
+    let slots_ahead = target_slot as f64 - current_slot as f64;
+    Utc::now() + chrono::Duration::milliseconds((slots_ahead * ms_per_slot) as i64)
+}
+
+/// Formats a number of seconds as a compact duration like `1d 4h 12m`
+pub fn format_duration(total_secs: u64) -> String {
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3_600;
+    let minutes = (total_secs % 3_600) / 60;
+    let seconds = total_secs % 60;
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{seconds}s"),
+        (0, 0, _) => format!("{minutes}m {seconds}s"),
+        (0, _, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h {minutes}m"),
+    }
+}
+
 pub fn read_keypair_from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Keypair> {
     let path = path.as_ref();
     Keypair::read_from_file(path)
         .map_err(|e| anyhow!("Failed to read keypair from {}: {}", path.display(), e))
 }
 
+/// Signs a legacy or v0 message. The same key may be passed more than once
+/// (e.g. an authority that is also the wallet); it signs only once.
+pub fn sign_message(
+    message: VersionedMessage,
+    signers: &[&dyn Signer],
+) -> anyhow::Result<VersionedTransaction> {
+    let mut unique: Vec<&dyn Signer> = Vec::with_capacity(signers.len());
+    for signer in signers {
+        if !unique
+            .iter()
+            .any(|existing| existing.pubkey() == signer.pubkey())
+        {
+            unique.push(*signer);
+        }
+    }
+    Ok(VersionedTransaction::try_new(message, &unique)?)
+}
+
+/// Compiles and signs a transaction for the instructions: a v0 message when
+/// a lookup table is given, a legacy one otherwise
+pub fn compile_tx(
+    payer: &Pubkey,
+    instruction: &[Instruction],
+    lookup_table: Option<AddressLookupTableAccount>,
+    recent_blockhash: Hash,
+    signers: &[&dyn Signer],
+) -> anyhow::Result<VersionedTransaction> {
+    let message = match lookup_table {
+        Some(lookup_table) => VersionedMessage::V0(v0::Message::try_compile(
+            payer,
+            instruction,
+            &[lookup_table],
+            recent_blockhash,
+        )?),
+        None => VersionedMessage::Legacy(Message::new_with_blockhash(
+            instruction,
+            Some(payer),
+            &recent_blockhash,
+        )),
+    };
+    sign_message(message, signers)
+}
+
+/// Builds and signs a transaction for the instructions, compiling a v0
+/// message against the session's lookup table when one is selected
+pub async fn build_tx(
+    ctx: &ScillaContext,
+    instruction: &[Instruction],
+    signers: &[&dyn Signer],
+) -> anyhow::Result<VersionedTransaction> {
+    let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
+
+    let lookup_table = match ctx.lookup_table() {
+        Some(lookup_table) => {
+            let table = fetch_lookup_table(ctx, &lookup_table).await?;
+            Some(AddressLookupTableAccount {
+                key: lookup_table,
+                addresses: table.addresses.into_owned(),
+            })
+        }
+        None => None,
+    };
+    compile_tx(
+        ctx.pubkey(),
+        instruction,
+        lookup_table,
+        recent_blockhash,
+        signers,
+    )
+}
+
+/// Signs and sends the instructions (see [`build_tx`]) and waits until the
+/// transaction reaches the client's commitment
 pub async fn build_and_send_tx(
     ctx: &ScillaContext,
     instruction: &[Instruction],
     signers: &[&dyn Signer],
 ) -> anyhow::Result<Signature> {
-    let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
-    let message = Message::new(instruction, Some(ctx.pubkey()));
-    let mut tx = Transaction::new_unsigned(message);
-    tx.try_sign(&signers.to_vec(), recent_blockhash)?;
+    let tx = build_tx(ctx, instruction, signers).await?;
+
+    let signature = ctx.rpc().send_transaction(&tx).await?;
 
-    let signature = ctx.rpc().send_and_confirm_transaction(&tx).await?;
+    let report = track_confirmation(
+        ctx,
+        &tx,
+        &TrackOptions::for_commitment(ctx.rpc().commitment()),
+        |_| {},
+    )
+    .await?;
 
-    Ok(signature)
+    match report.outcome {
+        TrackOutcome::Landed => Ok(signature),
+        TrackOutcome::Failed(err) => bail!("Transaction {signature} failed: {err}"),
+        TrackOutcome::Expired => {
+            bail!("Transaction {signature} was dropped: its blockhash expired before it landed")
+        }
+    }
+}
+
+/// Fetches and deserializes an address lookup table
+pub async fn fetch_lookup_table(
+    ctx: &ScillaContext,
+    lookup_table: &Pubkey,
+) -> anyhow::Result<AddressLookupTable<'static>> {
+    let account = ctx
+        .rpc()
+        .get_account(lookup_table)
+        .await
+        .map_err(|_| anyhow!("{lookup_table} account does not exist"))?;
+
+    if account.owner != address_lookup_table::id() {
+        bail!("{lookup_table} is not an address lookup table");
+    }
+
+    let table = AddressLookupTable::deserialize(&account.data)
+        .map_err(|e| anyhow!("Failed to deserialize lookup table {lookup_table}: {e}"))?;
+
+    Ok(AddressLookupTable {
+        meta: table.meta,
+        addresses: table.addresses.into_owned().into(),
+    })
 }
 
 /// Fetches account data and current epoch info in parallel.
@@ -130,6 +541,29 @@ pub async fn fetch_account_with_epoch(
     )
 }
 
+/// Websocket endpoint matching an RPC URL: `ws`/`wss` instead of
+/// `http`/`https`, and the validator's default RPC port 8899 moved to its
+/// pubsub port 8900. Any other port is kept, since providers behind a proxy
+/// serve both on the same one
+pub fn websocket_url(rpc_url: &str) -> Option<String> {
+    let (scheme, rest) = rpc_url.split_once("://")?;
+    let scheme = match scheme {
+        "http" => "ws",
+        "https" => "wss",
+        "ws" | "wss" => return Some(rpc_url.to_string()),
+        _ => return None,
+    };
+
+    let authority_end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let authority = match authority.strip_suffix(":8899") {
+        Some(host) => format!("{host}:8900"),
+        None => authority.to_string(),
+    };
+
+    Some(format!("{scheme}://{authority}{path}"))
+}
+
 /// Generic helper to deserialize bincode data with consistent error
 /// context
 pub fn bincode_deserialize<T>(data: &[u8], ctx: &str) -> anyhow::Result<T>
@@ -189,6 +623,45 @@ mod tests {
         solana_transaction::versioned::VersionedTransaction,
     };
 
+    #[test]
+    fn test_csv_round_trip() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("rows.csv");
+        let rows = vec![
+            vec!["plain".to_string(), "with, comma".to_string()],
+            vec!["say \"hi\"".to_string(), String::new()],
+        ];
+
+        write_csv(&path, &["a", "b"], &rows)?;
+        let (headers, read_rows) = read_csv(&path)?;
+
+        assert_eq!(headers, vec!["a", "b"]);
+        assert_eq!(read_rows, rows);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_websocket_url() {
+        assert_eq!(
+            websocket_url("https://api.devnet.solana.com").as_deref(),
+            Some("wss://api.devnet.solana.com")
+        );
+        assert_eq!(
+            websocket_url("http://127.0.0.1:8899").as_deref(),
+            Some("ws://127.0.0.1:8900")
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com:443").as_deref(),
+            Some("wss://rpc.example.com:443")
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com/?api-key=abc").as_deref(),
+            Some("wss://rpc.example.com/?api-key=abc")
+        );
+        assert_eq!(websocket_url("localhost:8899"), None);
+    }
+
     #[test]
     fn test_lamports_to_sol_exact_one_sol() {
         assert_eq!(lamports_to_sol(1_000_000_000), 1.0);
@@ -201,6 +674,225 @@ mod tests {
         assert!(result > 0.0, "Should handle u64::MAX without panic");
         assert!(result < f64::INFINITY, "Should not overflow to infinity");
     }
+
+    #[test]
+    fn test_pubkey_list_mixed_separators() -> anyhow::Result<()> {
+        let list: PubkeyList = format!(
+            "{}, {}\n{}",
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique()
+        )
+        .parse()?;
+        assert_eq!(list.pubkeys().len(), 3);
+        assert!(" , ".parse::<PubkeyList>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_epoch_target_parsing() -> anyhow::Result<()> {
+        assert_eq!("epoch 800".parse::<EpochTarget>()?, EpochTarget::Epoch(800));
+        assert_eq!(
+            "  Slot   350000000 ".parse::<EpochTarget>()?,
+            EpochTarget::Slot(350_000_000)
+        );
+        assert!("800".parse::<EpochTarget>().is_err());
+        assert!("block 5".parse::<EpochTarget>().is_err());
+        assert!("epoch -1".parse::<EpochTarget>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_list_rejects_non_http() -> anyhow::Result<()> {
+        let list: UrlList = "https://a.example, http://b.example:8899".parse()?;
+        assert_eq!(list.urls(), ["https://a.example", "http://b.example:8899"]);
+        assert!("ws://a.example".parse::<UrlList>().is_err());
+        assert!(" , ".parse::<UrlList>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stake_by_version() {
+        let distribution = stake_by_version([
+            ("2.3.1", 10),
+            ("3.0.0", 50),
+            ("2.3.1", 30),
+            ("3.0.0", 0),
+            ("unknown", 0),
+        ]);
+
+        assert_eq!(
+            distribution,
+            vec![
+                VersionStake {
+                    version: "3.0.0".to_string(),
+                    nodes: 2,
+                    stake: 50
+                },
+                VersionStake {
+                    version: "2.3.1".to_string(),
+                    nodes: 2,
+                    stake: 40
+                },
+                VersionStake {
+                    version: "unknown".to_string(),
+                    nodes: 1,
+                    stake: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sign_message_with_duplicate_signers() -> anyhow::Result<()> {
+        let wallet = Keypair::new();
+        let other = Pubkey::new_unique();
+        let instruction =
+            solana_system_interface::instruction::transfer(&wallet.pubkey(), &other, 1);
+        let blockhash = Default::default();
+
+        let legacy = VersionedMessage::Legacy(Message::new_with_blockhash(
+            std::slice::from_ref(&instruction),
+            Some(&wallet.pubkey()),
+            &blockhash,
+        ));
+        let tx = sign_message(legacy, &[&wallet, &wallet])?;
+        assert_eq!(
+            tx.signatures,
+            vec![wallet.sign_message(&tx.message.serialize())]
+        );
+
+        let v0 = VersionedMessage::V0(v0::Message::try_compile(
+            &wallet.pubkey(),
+            &[instruction],
+            &[],
+            blockhash,
+        )?);
+        let tx = sign_message(v0, &[&wallet, &wallet])?;
+        assert_eq!(
+            tx.signatures,
+            vec![wallet.sign_message(&tx.message.serialize())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_compile_tx_with_lookup_table_and_duplicate_signers() -> anyhow::Result<()> {
+        let wallet = Keypair::new();
+        let authority = Keypair::new();
+        let recipient = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![
+                solana_instruction::AccountMeta::new(wallet.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(authority.pubkey(), true),
+                solana_instruction::AccountMeta::new(recipient, false),
+            ],
+        );
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![recipient],
+        };
+
+        // The wallet signs both as fee payer and as an authority
+        let tx = compile_tx(
+            &wallet.pubkey(),
+            &[instruction],
+            Some(lookup_table),
+            Hash::default(),
+            &[&wallet, &authority, &wallet],
+        )?;
+        let VersionedMessage::V0(message) = &tx.message else {
+            panic!("expected a v0 message");
+        };
+        assert_eq!(message.address_table_lookups.len(), 1);
+        let signed = tx.message.serialize();
+        assert_eq!(
+            tx.signatures,
+            vec![
+                wallet.sign_message(&signed),
+                authority.sign_message(&signed)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_annualized_apy_zero_rate() {
+        assert_eq!(annualized_apy(0.0, epochs_per_year(432_000)), 0.0);
+    }
+
+    #[test]
+    fn test_validators_to_exceed_one_third() {
+        // 40% alone exceeds a third; two are needed to pass two thirds
+        let stakes = [40, 30, 20, 10];
+        assert_eq!(validators_to_exceed(&stakes, 1.0 / 3.0), 1);
+        assert_eq!(validators_to_exceed(&stakes, 2.0 / 3.0), 2);
+        assert_eq!(validators_to_exceed(&[], 1.0 / 3.0), 0);
+    }
+
+    #[test]
+    fn test_transactions_per_second() {
+        let sample = |num_transactions, num_non_vote_transactions| RpcPerfSample {
+            slot: 0,
+            num_transactions,
+            num_non_vote_transactions,
+            num_slots: 150,
+            sample_period_secs: 60,
+        };
+
+        assert_eq!(
+            transactions_per_second(&[sample(6_000, Some(1_200)), sample(3_000, Some(600))]),
+            Some((75.0, Some(15.0)))
+        );
+        // One sample without the non-vote count hides the split
+        assert_eq!(
+            transactions_per_second(&[sample(6_000, Some(1_200)), sample(3_000, None)]),
+            Some((75.0, None))
+        );
+        assert_eq!(transactions_per_second(&[]), None);
+    }
+
+    #[test]
+    fn test_average_slot_time_ms() {
+        let sample = |num_slots, sample_period_secs| RpcPerfSample {
+            slot: 0,
+            num_transactions: 0,
+            num_non_vote_transactions: None,
+            num_slots,
+            sample_period_secs,
+        };
+        assert_eq!(
+            average_slot_time_ms(&[sample(150, 60), sample(150, 60)]),
+            Some(400.0)
+        );
+        assert_eq!(average_slot_time_ms(&[]), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(3_725), "1h 2m");
+        assert_eq!(format_duration(90_061), "1d 1h 1m");
+    }
+
+    #[test]
+    fn test_write_csv_quotes_delimiters() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("out.csv");
+        write_csv(
+            &path,
+            &["a", "b"],
+            &[vec!["1,5".to_string(), "say \"hi\"".to_string()]],
+        )?;
+        assert_eq!(
+            fs::read_to_string(path)?,
+            "a,b\n\"1,5\",\"say \"\"hi\"\"\"\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_decode_base64_memo_transaction() -> anyhow::Result<()> {
         // Fixture: Real memo transaction from Solana devnet
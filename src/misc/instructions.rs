@@ -0,0 +1,428 @@
+use {
+    crate::{
+        constants::{MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID},
+        misc::programs::program_label,
+    },
+    anyhow::anyhow,
+    serde_json::Value,
+    solana_message::{AccountKeys, VersionedMessage, compiled_instruction::CompiledInstruction},
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{compute_budget, system_program},
+    solana_transaction_status::{
+        UiCompiledInstruction, UiInstruction, UiParsedInstruction, UiPartiallyDecodedInstruction,
+        parse_instruction::{self, ParsedInstruction},
+    },
+};
+
+/// An instruction resolved to a program name, instruction name and arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    pub program: String,
+    pub name: String,
+    pub args: Vec<(String, String)>,
+    pub accounts: Vec<String>,
+}
+
+impl DecodedInstruction {
+    fn unknown(program_id: &Pubkey, data: &[u8], accounts: Vec<String>) -> Self {
+        Self {
+            program: program_label(program_id),
+            name: "Unknown".to_string(),
+            args: vec![("Data".to_string(), bs58::encode(data).into_string())],
+            accounts,
+        }
+    }
+}
+
+/// Decodes raw instruction data for the programs Scilla understands.
+///
+/// Returns the instruction name and its arguments, or `None` when the program
+/// or the instruction layout is not recognised.
+pub fn decode_instruction_data(
+    program_id: &Pubkey,
+    data: &[u8],
+) -> Option<(String, Vec<(String, String)>)> {
+    if *program_id == compute_budget::id() {
+        decode_compute_budget(data)
+    } else if *program_id == Pubkey::from_str_const(MEMO_PROGRAM_ID)
+        || *program_id == Pubkey::from_str_const(MEMO_V1_PROGRAM_ID)
+    {
+        decode_memo(data)
+    } else {
+        None
+    }
+}
+
+/// Decodes an instruction from raw parts, falling back to the base58 data
+pub fn decode_raw_instruction(
+    program_id: &Pubkey,
+    data: &[u8],
+    accounts: Vec<String>,
+) -> DecodedInstruction {
+    match decode_instruction_data(program_id, data) {
+        Some((name, args)) => DecodedInstruction {
+            program: program_label(program_id),
+            name,
+            args,
+            accounts,
+        },
+        None => DecodedInstruction::unknown(program_id, data, accounts),
+    }
+}
+
+/// Decodes an instruction as returned by the RPC in any encoding.
+///
+/// `account_keys` is the full key list of the transaction (including any
+/// addresses loaded from lookup tables) and is only used for compiled
+/// instructions, which reference accounts by index.
+pub fn decode_ui_instruction(
+    instruction: &UiInstruction,
+    account_keys: &[String],
+) -> anyhow::Result<DecodedInstruction> {
+    Ok(match instruction {
+        UiInstruction::Compiled(compiled) => decode_compiled(compiled, account_keys)?,
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => {
+            decode_partially_decoded(partial)
+        }
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+            from_parsed_instruction(parsed)
+        }
+    })
+}
+
+fn from_parsed_instruction(parsed: &ParsedInstruction) -> DecodedInstruction {
+    let program = parsed
+        .program_id
+        .parse::<Pubkey>()
+        .map(|id| program_label(&id))
+        .unwrap_or_else(|_| parsed.program.clone());
+    let (name, args, accounts) = from_parsed_json(&parsed.parsed);
+
+    DecodedInstruction {
+        program,
+        name,
+        args,
+        accounts,
+    }
+}
+
+/// Decodes a compiled message instruction against its full account key list.
+pub fn decode_compiled_instruction(
+    instruction: &CompiledInstruction,
+    account_keys: &[Pubkey],
+) -> DecodedInstruction {
+    decode_compiled_with(instruction, account_keys, |idx| format!("<index {idx}>"))
+}
+
+/// Decodes a compiled instruction of a message that was never sent, without
+/// any network access.
+///
+/// `static_keys` holds only the static keys of a v0 message, so accounts past
+/// them are loaded from lookup tables; they are shown by their position in the
+/// loaded addresses and the instruction is only decoded from its data.
+pub fn decode_unresolved_instruction(
+    instruction: &CompiledInstruction,
+    static_keys: &[Pubkey],
+) -> DecodedInstruction {
+    decode_compiled_with(instruction, static_keys, |idx| {
+        format!("<lookup {}>", idx - static_keys.len())
+    })
+}
+
+fn decode_compiled_with(
+    instruction: &CompiledInstruction,
+    account_keys: &[Pubkey],
+    missing_label: impl Fn(usize) -> String,
+) -> DecodedInstruction {
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|idx| match account_keys.get(*idx as usize) {
+            Some(key) => key.to_string(),
+            None => missing_label(*idx as usize),
+        })
+        .collect();
+
+    let Some(program_id) = account_keys.get(instruction.program_id_index as usize) else {
+        return DecodedInstruction {
+            program: format!("<index {}>", instruction.program_id_index),
+            name: "Unknown".to_string(),
+            args: vec![(
+                "Data".to_string(),
+                bs58::encode(&instruction.data).into_string(),
+            )],
+            accounts,
+        };
+    };
+
+    // The parsers index straight into the key list, so only hand them
+    // instructions whose accounts are all known
+    let all_resolved = instruction
+        .accounts
+        .iter()
+        .all(|idx| (*idx as usize) < account_keys.len());
+    if all_resolved
+        && let Ok(parsed) = parse_instruction::parse(
+            program_id,
+            instruction,
+            &AccountKeys::new(account_keys, None),
+            None,
+        )
+    {
+        return DecodedInstruction {
+            accounts,
+            ..from_parsed_instruction(&parsed)
+        };
+    }
+
+    decode_raw_instruction(program_id, &instruction.data, accounts)
+}
+
+/// Nonce account used by a durable-nonce transaction, i.e. one whose first
+/// instruction is a system `AdvanceNonceAccount`
+pub fn advance_nonce_account(message: &VersionedMessage) -> Option<Pubkey> {
+    const ADVANCE_NONCE_ACCOUNT_TAG: u32 = 4;
+
+    let keys = message.static_account_keys();
+    let first = message.instructions().first()?;
+    let program_id = keys.get(first.program_id_index as usize)?;
+
+    if *program_id != system_program::id()
+        || first.data.get(..4)? != ADVANCE_NONCE_ACCOUNT_TAG.to_le_bytes()
+    {
+        return None;
+    }
+
+    keys.get(*first.accounts.first()? as usize).copied()
+}
+
+fn decode_compiled(
+    compiled: &UiCompiledInstruction,
+    account_keys: &[String],
+) -> anyhow::Result<DecodedInstruction> {
+    let data = bs58::decode(&compiled.data).into_vec().unwrap_or_default();
+    let keys = account_keys
+        .iter()
+        .map(|key| {
+            key.parse()
+                .map_err(|_| anyhow!("Invalid account key in transaction: {key}"))
+        })
+        .collect::<anyhow::Result<Vec<Pubkey>>>()?;
+
+    Ok(decode_compiled_instruction(
+        &CompiledInstruction {
+            program_id_index: compiled.program_id_index,
+            accounts: compiled.accounts.clone(),
+            data,
+        },
+        &keys,
+    ))
+}
+
+fn decode_partially_decoded(partial: &UiPartiallyDecodedInstruction) -> DecodedInstruction {
+    let data = bs58::decode(&partial.data).into_vec().unwrap_or_default();
+
+    match partial.program_id.parse::<Pubkey>() {
+        Ok(program_id) => decode_raw_instruction(&program_id, &data, partial.accounts.clone()),
+        Err(_) => DecodedInstruction {
+            program: partial.program_id.clone(),
+            name: "Unknown".to_string(),
+            args: vec![("Data".to_string(), partial.data.clone())],
+            accounts: partial.accounts.clone(),
+        },
+    }
+}
+
+/// Splits the RPC's `jsonParsed` representation into name, arguments and
+/// accounts.
+///
+/// The RPC emits either `{ "type": .., "info": { .. } }` or, for the memo
+/// program, a bare string. Arguments that look like addresses are also listed
+/// as accounts since parsed instructions don't carry the raw account list.
+fn from_parsed_json(parsed: &Value) -> (String, Vec<(String, String)>, Vec<String>) {
+    match parsed {
+        Value::String(memo) => (
+            "Memo".to_string(),
+            vec![("Memo".to_string(), memo.clone())],
+            Vec::new(),
+        ),
+        Value::Object(map) => {
+            let name = map
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or("Unknown")
+                .to_string();
+
+            let mut args = Vec::new();
+            let mut accounts = Vec::new();
+            if let Some(Value::Object(info)) = map.get("info") {
+                for (key, value) in info {
+                    let rendered = render_json_value(value);
+                    if rendered.parse::<Pubkey>().is_ok() {
+                        accounts.push(rendered.clone());
+                    }
+                    args.push((key.clone(), rendered));
+                }
+            }
+
+            (name, args, accounts)
+        }
+        other => (
+            "Unknown".to_string(),
+            vec![("Parsed".to_string(), other.to_string())],
+            Vec::new(),
+        ),
+    }
+}
+
+fn render_json_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| format!("{key}: {}", render_json_value(value)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn decode_compute_budget(data: &[u8]) -> Option<(String, Vec<(String, String)>)> {
+    let (tag, rest) = data.split_first()?;
+
+    let decoded = match tag {
+        1 => (
+            "RequestHeapFrame",
+            vec![("Bytes".to_string(), read_u32(rest)?.to_string())],
+        ),
+        2 => (
+            "SetComputeUnitLimit",
+            vec![("Units".to_string(), read_u32(rest)?.to_string())],
+        ),
+        3 => (
+            "SetComputeUnitPrice",
+            vec![(
+                "Micro-lamports per CU".to_string(),
+                read_u64(rest)?.to_string(),
+            )],
+        ),
+        4 => (
+            "SetLoadedAccountsDataSizeLimit",
+            vec![("Bytes".to_string(), read_u32(rest)?.to_string())],
+        ),
+        _ => return None,
+    };
+
+    Some((decoded.0.to_string(), decoded.1))
+}
+
+fn decode_memo(data: &[u8]) -> Option<(String, Vec<(String, String)>)> {
+    let memo = std::str::from_utf8(data).ok()?;
+    Some((
+        "Memo".to_string(),
+        vec![("Memo".to_string(), memo.to_string())],
+    ))
+}
+
+fn read_u32(data: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(..4)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(..8)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_decode_compute_budget() {
+        let mut data = vec![3];
+        data.extend(5_000u64.to_le_bytes());
+
+        let (name, args) =
+            decode_instruction_data(&compute_budget::id(), &data).expect("should decode");
+        assert_eq!(name, "SetComputeUnitPrice");
+        assert_eq!(args[0].1, "5000");
+
+        // Truncated payloads are not decoded
+        assert!(decode_instruction_data(&compute_budget::id(), &[2, 1]).is_none());
+    }
+
+    #[test]
+    fn test_decode_compiled_resolves_accounts() -> anyhow::Result<()> {
+        let program = compute_budget::id().to_string();
+        let mut data = vec![2];
+        data.extend(200_000u32.to_le_bytes());
+
+        let instruction = UiInstruction::Compiled(UiCompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0, 7],
+            data: bs58::encode(&data).into_string(),
+            stack_height: None,
+        });
+        let payer = Pubkey::new_unique().to_string();
+        let keys = vec![payer.clone(), program];
+
+        let decoded = decode_ui_instruction(&instruction, &keys)?;
+        assert_eq!(decoded.program, "Compute Budget Program");
+        assert_eq!(decoded.name, "SetComputeUnitLimit");
+        assert_eq!(decoded.accounts, vec![payer, "<index 7>".to_string()]);
+
+        let bad_keys = vec!["not-a-pubkey".to_string(), keys[1].clone()];
+        assert!(decode_ui_instruction(&instruction, &bad_keys).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_compiled_instruction_offline() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let keys = vec![from, to, solana_sdk_ids::system_program::id()];
+
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend(1_000u64.to_le_bytes());
+        let transfer = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        };
+
+        let decoded = decode_compiled_instruction(&transfer, &keys);
+        assert_eq!(decoded.program, "System Program");
+        assert_eq!(decoded.name, "transfer");
+        assert!(
+            decoded
+                .args
+                .contains(&("lamports".to_string(), "1000".to_string()))
+        );
+
+        // Accounts behind a lookup table can't be resolved offline
+        let unresolved = CompiledInstruction {
+            accounts: vec![0, 4],
+            ..transfer
+        };
+        let decoded = decode_unresolved_instruction(&unresolved, &keys);
+        assert_eq!(decoded.name, "Unknown");
+        assert_eq!(decoded.accounts[1], "<lookup 1>");
+    }
+
+    #[test]
+    fn test_from_parsed_json() {
+        let source = Pubkey::new_unique().to_string();
+        let parsed = json!({
+            "type": "transfer",
+            "info": { "source": source, "lamports": 42 }
+        });
+
+        let (name, args, accounts) = from_parsed_json(&parsed);
+        assert_eq!(name, "transfer");
+        assert!(args.contains(&("lamports".to_string(), "42".to_string())));
+        assert_eq!(accounts, vec![source]);
+
+        let (name, args, _) = from_parsed_json(&json!("gm"));
+        assert_eq!(name, "Memo");
+        assert_eq!(args[0].1, "gm");
+    }
+}
@@ -0,0 +1,162 @@
+use {
+    crate::{constants::MAX_TRANSACTION_SIZE, misc::batch::transaction_size},
+    anyhow::{anyhow, bail},
+    sha2::{Digest, Sha256},
+    solana_loader_v3_interface::{instruction::write, state::UpgradeableLoaderState},
+    solana_pubkey::Pubkey,
+};
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+
+/// Loader v4 program accounts start with the deploy slot, the authority (or
+/// next version once finalized) and the status, each padded to 8 bytes
+const LOADER_V4_HEADER_LEN: usize = 48;
+
+/// Checks `data` looks like a compiled program before any rent is spent on it
+pub fn check_program_elf(data: &[u8]) -> anyhow::Result<()> {
+    if data.is_empty() {
+        bail!("Program file is empty");
+    }
+    if !data.starts_with(ELF_MAGIC) {
+        bail!("Program file is not an ELF shared object");
+    }
+    Ok(())
+}
+
+/// Largest number of program bytes a single buffer write transaction can carry
+pub fn write_chunk_size(payer: &Pubkey, buffer: &Pubkey, authority: &Pubkey) -> usize {
+    let empty = write(buffer, authority, 0, Vec::new());
+    // The instruction data length prefix grows from one to two bytes once the
+    // chunk pushes it past 127
+    MAX_TRANSACTION_SIZE - transaction_size(payer, &[&empty]) - 1
+}
+
+/// Chunks of `program` still to be written as (offset, bytes), skipping those
+/// `written` (the buffer's current contents) already holds
+pub fn pending_writes<'a>(
+    program: &'a [u8],
+    written: &[u8],
+    chunk_size: usize,
+) -> Vec<(u32, &'a [u8])> {
+    program
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(idx, chunk)| (idx * chunk_size, chunk))
+        .filter(|(offset, chunk)| written.get(*offset..offset + chunk.len()) != Some(*chunk))
+        .map(|(offset, chunk)| (offset as u32, chunk))
+        .collect()
+}
+
+/// Program bytes held by a buffer account, after its metadata
+pub fn buffer_program_data(data: &[u8]) -> &[u8] {
+    data.get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+        .unwrap_or_default()
+}
+
+/// Room for program bytes in a program data account, after its metadata
+pub fn programdata_capacity(data_len: usize) -> usize {
+    data_len.saturating_sub(UpgradeableLoaderState::size_of_programdata_metadata())
+}
+
+/// Header of a loader v4 program account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoaderV4Header {
+    pub slot: u64,
+    pub authority_address_or_next_version: Pubkey,
+    pub status: &'static str,
+}
+
+/// Splits a loader v4 program account into its header and ELF bytes
+pub fn parse_loader_v4(data: &[u8]) -> anyhow::Result<(LoaderV4Header, &[u8])> {
+    if data.len() < LOADER_V4_HEADER_LEN {
+        bail!("Loader v4 account is too short to hold a program");
+    }
+    let word = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    let status = match word(40) {
+        0 => "Retracted",
+        1 => "Deployed",
+        2 => "Finalized",
+        other => return Err(anyhow!("Unknown loader v4 status {other}")),
+    };
+    let header = LoaderV4Header {
+        slot: word(0),
+        authority_address_or_next_version: Pubkey::try_from(&data[8..40])?,
+        status,
+    };
+
+    Ok((header, &data[LOADER_V4_HEADER_LEN..]))
+}
+
+/// Hex SHA-256 of a program's ELF with trailing zero bytes stripped, the same
+/// hash `solana-verify` reports. Not the SHA-256 of the exact bytes: an ELF
+/// that itself ends in zeros hashes differently from `sha256sum`
+pub fn elf_hash(elf: &[u8]) -> String {
+    let end = elf
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |idx| idx + 1);
+    format!("{:x}", Sha256::digest(&elf[..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_writes() {
+        let program: Vec<u8> = (0..10).collect();
+        assert_eq!(pending_writes(&program, &[], 4).len(), 3);
+
+        // A partial upload: first chunk written, second zeroed, third partial
+        let mut written = vec![0u8; 10];
+        written[..4].copy_from_slice(&program[..4]);
+        written[8] = 8;
+        let pending = pending_writes(&program, &written, 4);
+        assert_eq!(pending, vec![(4, &program[4..8]), (8, &program[8..10])]);
+
+        assert!(pending_writes(&program, &program, 4).is_empty());
+    }
+
+    #[test]
+    fn test_write_chunk_size_fills_transaction() {
+        let (payer, buffer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let chunk_size = write_chunk_size(&payer, &buffer, &payer);
+
+        let full = write(&buffer, &payer, 0, vec![0; chunk_size]);
+        assert_eq!(transaction_size(&payer, &[&full]), MAX_TRANSACTION_SIZE);
+    }
+
+    #[test]
+    fn test_elf_hash_ignores_padding() {
+        let elf = b"\x7fELF\x02\x01".to_vec();
+        let mut padded = elf.clone();
+        padded.extend([0; 64]);
+
+        assert_eq!(elf_hash(&elf), elf_hash(&padded));
+        assert_ne!(elf_hash(&elf), elf_hash(b"\x7fELF\x02\x02"));
+    }
+
+    #[test]
+    fn test_parse_loader_v4() -> anyhow::Result<()> {
+        let authority = Pubkey::new_unique();
+        let mut data = 42u64.to_le_bytes().to_vec();
+        data.extend(authority.to_bytes());
+        data.extend(1u64.to_le_bytes());
+        data.extend(b"\x7fELF");
+
+        let (header, elf) = parse_loader_v4(&data)?;
+        assert_eq!(header.slot, 42);
+        assert_eq!(header.authority_address_or_next_version, authority);
+        assert_eq!(header.status, "Deployed");
+        assert_eq!(elf, b"\x7fELF");
+        assert!(parse_loader_v4(&data[..40]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_program_elf() {
+        assert!(check_program_elf(b"\x7fELF\x02\x01").is_ok());
+        assert!(check_program_elf(b"").is_err());
+        assert!(check_program_elf(b"#!/bin/sh").is_err());
+    }
+}
@@ -1 +1,12 @@
+pub mod accounts;
+pub mod batch;
+pub mod confirmation;
+pub mod health;
 pub mod helpers;
+pub mod instructions;
+pub mod loader;
+pub mod programs;
+pub mod stake;
+pub mod sysvars;
+pub mod token;
+pub mod validator_info;
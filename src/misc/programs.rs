@@ -0,0 +1,46 @@
+use {
+    crate::constants::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{
+        address_lookup_table, bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable,
+        compute_budget, config, ed25519_program, loader_v4, secp256k1_program, stake,
+        system_program, vote,
+    },
+};
+
+/// Human readable name for programs Scilla knows about
+pub fn known_program_name(program_id: &Pubkey) -> Option<&'static str> {
+    let name = match *program_id {
+        id if id == system_program::id() => "System Program",
+        id if id == stake::id() => "Stake Program",
+        id if id == vote::id() => "Vote Program",
+        id if id == compute_budget::id() => "Compute Budget Program",
+        id if id == config::id() => "Config Program",
+        id if id == address_lookup_table::id() => "Address Lookup Table Program",
+        id if id == bpf_loader::id() => "BPF Loader",
+        id if id == bpf_loader_deprecated::id() => "BPF Loader (deprecated)",
+        id if id == bpf_loader_upgradeable::id() => "BPF Upgradeable Loader",
+        id if id == loader_v4::id() => "Loader v4",
+        id if id == ed25519_program::id() => "Ed25519 Program",
+        id if id == secp256k1_program::id() => "Secp256k1 Program",
+        id if id == Pubkey::from_str_const(TOKEN_PROGRAM_ID) => "Token Program",
+        id if id == Pubkey::from_str_const(TOKEN_2022_PROGRAM_ID) => "Token-2022 Program",
+        id if id == Pubkey::from_str_const(ASSOCIATED_TOKEN_PROGRAM_ID) => {
+            "Associated Token Program"
+        }
+        id if id == Pubkey::from_str_const(MEMO_PROGRAM_ID) => "Memo Program",
+        id if id == Pubkey::from_str_const(MEMO_V1_PROGRAM_ID) => "Memo Program (v1)",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Program name if known, otherwise its address
+pub fn program_label(program_id: &Pubkey) -> String {
+    known_program_name(program_id)
+        .map(str::to_string)
+        .unwrap_or_else(|| program_id.to_string())
+}
@@ -0,0 +1,285 @@
+use {
+    solana_stake_interface::{
+        stake_history::{StakeHistory, StakeHistoryEntry, StakeHistoryGetEntry},
+        state::{Delegation, warmup_cooldown_rate},
+    },
+    std::collections::BTreeMap,
+};
+
+/// One epoch of cluster stake history with values derived from its neighbours
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakeHistoryRow {
+    pub epoch: u64,
+    pub entry: StakeHistoryEntry,
+    /// Change in effective stake since the previous epoch, if it is recorded
+    pub effective_delta: Option<i128>,
+}
+
+impl StakeHistoryRow {
+    /// Activating stake as a share of effective stake, in percent
+    pub fn warmup_rate(&self) -> f64 {
+        share_of_effective(self.entry.activating, self.entry.effective)
+    }
+
+    /// Deactivating stake as a share of effective stake, in percent
+    pub fn cooldown_rate(&self) -> f64 {
+        share_of_effective(self.entry.deactivating, self.entry.effective)
+    }
+}
+
+fn share_of_effective(amount: u64, effective: u64) -> f64 {
+    if effective == 0 {
+        return 0.0;
+    }
+    amount as f64 / effective as f64 * 100.0
+}
+
+/// Stake history rows between `from` and `to` (inclusive), oldest first.
+///
+/// Without bounds the newest `default_limit` epochs are returned; with only
+/// `from` everything from that epoch on.
+pub fn stake_history_rows(
+    history: &StakeHistory,
+    from: Option<u64>,
+    to: Option<u64>,
+    default_limit: usize,
+) -> Vec<StakeHistoryRow> {
+    // Entries are stored newest first
+    let in_range = history
+        .iter()
+        .filter(|(epoch, _)| from.is_none_or(|from| *epoch >= from))
+        .filter(|(epoch, _)| to.is_none_or(|to| *epoch <= to));
+
+    let mut selected: Vec<&(u64, StakeHistoryEntry)> = if from.is_none() {
+        in_range.take(default_limit).collect()
+    } else {
+        in_range.collect()
+    };
+    selected.reverse();
+
+    selected
+        .into_iter()
+        .map(|(epoch, entry)| StakeHistoryRow {
+            epoch: *epoch,
+            entry: entry.clone(),
+            effective_delta: epoch
+                .checked_sub(1)
+                .and_then(|previous| history.get(previous))
+                .map(|previous| entry.effective as i128 - previous.effective as i128),
+        })
+        .collect()
+}
+
+/// Horizontal bars for `values`, scaled between their minimum and maximum so
+/// small changes on a large base stay visible
+pub fn bar_chart(values: &[u64], width: usize) -> Vec<String> {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return Vec::new();
+    };
+    let span = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            let filled = if span == 0 {
+                width
+            } else {
+                // Keep at least one cell so the minimum is still drawn
+                1 + ((value - min) as u128 * (width as u128 - 1) / span as u128) as usize
+            };
+            format!("{}{}", "█".repeat(filled), " ".repeat(width - filled))
+        })
+        .collect()
+}
+
+/// Stake history extended past its latest entry with projected cluster stake
+struct ProjectedHistory<'a> {
+    history: &'a StakeHistory,
+    projected: BTreeMap<u64, StakeHistoryEntry>,
+}
+
+impl StakeHistoryGetEntry for ProjectedHistory<'_> {
+    fn get_entry(&self, epoch: u64) -> Option<StakeHistoryEntry> {
+        self.projected
+            .get(&epoch)
+            .cloned()
+            .or_else(|| self.history.get_entry(epoch))
+    }
+}
+
+/// The delegation's stake at the start of one epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastEpoch {
+    pub epoch: u64,
+    pub effective: u64,
+    pub activating: u64,
+    pub deactivating: u64,
+}
+
+/// Simulates `delegation` from `current_epoch` until it is fully active or
+/// fully deactivated, for at most `max_epochs` epochs.
+///
+/// Cluster stake after the latest history entry is projected with the stake
+/// program's warmup/cooldown rules assuming no further activations or
+/// deactivations besides this delegation's own, so the forecast is what the
+/// cluster would do if nothing else changed.
+pub fn forecast_delegation(
+    delegation: &Delegation,
+    history: &StakeHistory,
+    current_epoch: u64,
+    new_rate_activation_epoch: Option<u64>,
+    max_epochs: u64,
+) -> Vec<ForecastEpoch> {
+    let mut projected = BTreeMap::new();
+    if let Some((latest_epoch, latest)) = history.first() {
+        let mut previous = latest.clone();
+        for epoch in latest_epoch + 1..=current_epoch + max_epochs {
+            let rate = warmup_cooldown_rate(epoch, new_rate_activation_epoch);
+            let change = (previous.effective as f64 * rate) as u64;
+            let newly_effective = previous.activating.min(change);
+            let newly_not_effective = previous.deactivating.min(change);
+
+            let mut entry = StakeHistoryEntry {
+                effective: (previous.effective + newly_effective)
+                    .saturating_sub(newly_not_effective),
+                activating: previous.activating - newly_effective,
+                deactivating: previous.deactivating - newly_not_effective,
+            };
+            // A delegation changed in an epoch without a history entry yet is
+            // missing from the cluster totals
+            if epoch == delegation.activation_epoch {
+                entry.activating += delegation.stake;
+            }
+            if epoch == delegation.deactivation_epoch {
+                entry.deactivating += delegation
+                    .stake_activating_and_deactivating(epoch, history, new_rate_activation_epoch)
+                    .deactivating;
+            }
+
+            projected.insert(epoch, entry.clone());
+            previous = entry;
+        }
+    }
+    let history = ProjectedHistory { history, projected };
+
+    let mut forecast = Vec::new();
+    for epoch in current_epoch..=current_epoch + max_epochs {
+        let status = delegation.stake_activating_and_deactivating(
+            epoch,
+            &history,
+            new_rate_activation_epoch,
+        );
+        forecast.push(ForecastEpoch {
+            epoch,
+            effective: status.effective,
+            activating: status.activating,
+            deactivating: status.deactivating,
+        });
+
+        if status.activating == 0 && status.deactivating == 0 {
+            break;
+        }
+    }
+    forecast
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_pubkey::Pubkey};
+
+    fn history() -> StakeHistory {
+        let mut history = StakeHistory::default();
+        for (epoch, effective) in [(10, 1_000), (11, 1_100), (12, 1_050), (13, 1_200)] {
+            history.add(
+                epoch,
+                StakeHistoryEntry {
+                    effective,
+                    activating: 100,
+                    deactivating: 50,
+                },
+            );
+        }
+        history
+    }
+
+    #[test]
+    fn test_stake_history_rows() {
+        let history = history();
+
+        let latest = stake_history_rows(&history, None, None, 2);
+        assert_eq!(
+            latest.iter().map(|row| row.epoch).collect::<Vec<_>>(),
+            vec![12, 13]
+        );
+        assert_eq!(latest[0].effective_delta, Some(-50));
+        assert_eq!(latest[1].effective_delta, Some(150));
+
+        let range = stake_history_rows(&history, Some(10), Some(11), 2);
+        assert_eq!(
+            range.iter().map(|row| row.epoch).collect::<Vec<_>>(),
+            vec![10, 11]
+        );
+        assert_eq!(range[0].effective_delta, None);
+        assert_eq!(range[0].warmup_rate(), 10.0);
+        assert_eq!(range[0].cooldown_rate(), 5.0);
+    }
+
+    #[test]
+    fn test_forecast_activation() {
+        let mut history = StakeHistory::default();
+        history.add(
+            9,
+            StakeHistoryEntry {
+                effective: 1_000,
+                activating: 0,
+                deactivating: 0,
+            },
+        );
+        let delegation = Delegation::new(&Pubkey::new_unique(), 200, 10);
+
+        let forecast = forecast_delegation(&delegation, &history, 10, Some(0), 20);
+        assert_eq!(
+            forecast
+                .iter()
+                .map(|epoch| (epoch.epoch, epoch.effective, epoch.activating))
+                .collect::<Vec<_>>(),
+            vec![(10, 0, 200), (11, 90, 110), (12, 188, 12), (13, 200, 0)]
+        );
+    }
+
+    #[test]
+    fn test_forecast_deactivation() {
+        let mut history = StakeHistory::default();
+        for epoch in 0..10 {
+            history.add(
+                epoch,
+                StakeHistoryEntry {
+                    effective: 1_000,
+                    activating: 0,
+                    deactivating: 0,
+                },
+            );
+        }
+        // Bootstrap stake is fully effective from genesis
+        let delegation = Delegation {
+            activation_epoch: u64::MAX,
+            deactivation_epoch: 10,
+            ..Delegation::new(&Pubkey::new_unique(), 500, 0)
+        };
+
+        let forecast = forecast_delegation(&delegation, &history, 10, Some(0), 20);
+        let last = forecast.last().unwrap();
+        assert_eq!(forecast[0].deactivating, 500);
+        assert_eq!((last.effective, last.deactivating), (0, 0));
+        // 9% of the cluster per epoch: 90, then 90 of 410 remaining, ...
+        assert_eq!(forecast[1].deactivating, 410);
+    }
+
+    #[test]
+    fn test_bar_chart() {
+        let bars = bar_chart(&[100, 150, 200], 5);
+        assert_eq!(bars, vec!["█    ", "███  ", "█████"]);
+        assert_eq!(bar_chart(&[7, 7], 3), vec!["███", "███"]);
+        assert!(bar_chart(&[], 3).is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use {
+    crate::misc::helpers::{bincode_deserialize, bincode_deserialize_with_limit, lamports_to_sol},
+    solana_clock::Clock,
+    solana_epoch_rewards::EpochRewards,
+    solana_epoch_schedule::EpochSchedule,
+    solana_last_restart_slot::LastRestartSlot,
+    solana_message::Hash,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::sysvar,
+    solana_slot_hashes::SlotHashes,
+    solana_slot_history::{Check, SlotHistory},
+    solana_stake_interface::stake_history::StakeHistory,
+};
+
+/// Name of the sysvar stored at `pubkey`
+pub fn sysvar_name(pubkey: &Pubkey) -> Option<&'static str> {
+    let name = match *pubkey {
+        id if id == sysvar::clock::id() => "Clock",
+        id if id == sysvar::epoch_schedule::id() => "EpochSchedule",
+        id if id == sysvar::rent::id() => "Rent",
+        id if id == sysvar::epoch_rewards::id() => "EpochRewards",
+        id if id == sysvar::slot_hashes::id() => "SlotHashes",
+        id if id == sysvar::slot_history::id() => "SlotHistory",
+        id if id == sysvar::stake_history::id() => "StakeHistory",
+        id if id == sysvar::recent_blockhashes::id() => "RecentBlockhashes",
+        id if id == sysvar::last_restart_slot::id() => "LastRestartSlot",
+        id if id == sysvar::fees::id() => "Fees",
+        id if id == sysvar::rewards::id() => "Rewards",
+        id if id == sysvar::instructions::id() => "Instructions",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Decodes the deprecated RecentBlockhashes sysvar into (blockhash, lamports
+/// per signature) entries, newest first
+pub fn decode_recent_blockhashes(data: &[u8]) -> anyhow::Result<Vec<(Hash, u64)>> {
+    // Each entry is a blockhash followed by its single-field fee calculator
+    bincode_deserialize_with_limit(data.len() as u64, data, "recent blockhashes sysvar")
+}
+
+/// Whether each of the newest `window` slots tracked by `history` produced a
+/// block, newest first
+pub fn recent_slot_window(history: &SlotHistory, window: u64) -> Vec<(u64, bool)> {
+    let newest = history.newest();
+    let oldest = newest
+        .saturating_sub(window.saturating_sub(1))
+        .max(history.oldest());
+
+    (oldest..=newest)
+        .rev()
+        .map(|slot| (slot, history.check(slot) == Check::Found))
+        .collect()
+}
+
+/// Formats a unix timestamp as UTC, falling back to the raw number
+pub fn format_unix_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|datetime| datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Key fields of a sysvar account as label/value rows. Sysvars without a
+/// summary yield no rows.
+pub fn sysvar_fields(pubkey: &Pubkey, data: &[u8]) -> anyhow::Result<Vec<(String, String)>> {
+    let field = |label: &str, value: String| (label.to_string(), value);
+
+    let fields = match *pubkey {
+        id if id == sysvar::clock::id() => {
+            let clock: Clock = bincode_deserialize(data, "clock sysvar")?;
+            vec![
+                field("Slot", clock.slot.to_string()),
+                field("Epoch", clock.epoch.to_string()),
+                field(
+                    "Epoch Start Timestamp",
+                    format_unix_timestamp(clock.epoch_start_timestamp),
+                ),
+                field(
+                    "Leader Schedule Epoch",
+                    clock.leader_schedule_epoch.to_string(),
+                ),
+                field(
+                    "Unix Timestamp",
+                    format_unix_timestamp(clock.unix_timestamp),
+                ),
+            ]
+        }
+        id if id == sysvar::epoch_schedule::id() => {
+            let schedule: EpochSchedule = bincode_deserialize(data, "epoch schedule sysvar")?;
+            vec![
+                field("Slots per Epoch", schedule.slots_per_epoch.to_string()),
+                field(
+                    "Leader Schedule Slot Offset",
+                    schedule.leader_schedule_slot_offset.to_string(),
+                ),
+                field("Warmup", schedule.warmup.to_string()),
+                field(
+                    "First Normal Epoch",
+                    schedule.first_normal_epoch.to_string(),
+                ),
+                field("First Normal Slot", schedule.first_normal_slot.to_string()),
+            ]
+        }
+        id if id == sysvar::rent::id() => {
+            let rent: Rent = bincode_deserialize(data, "rent sysvar")?;
+            // The sysvar still stores the pre-SIMD-0194 fields; show them as
+            // they are on chain
+            #[allow(deprecated)]
+            let (lamports_per_byte_year, exemption_threshold, burn_percent) = (
+                rent.lamports_per_byte_year,
+                rent.exemption_threshold,
+                rent.burn_percent,
+            );
+            vec![
+                field("Lamports per Byte-Year", lamports_per_byte_year.to_string()),
+                field(
+                    "Exemption Threshold",
+                    format!("{exemption_threshold} years"),
+                ),
+                field("Burn Percent", format!("{burn_percent}%")),
+                field(
+                    "Rent-Exempt Minimum (0 bytes)",
+                    format!("{} lamports", rent.minimum_balance(0)),
+                ),
+            ]
+        }
+        id if id == sysvar::epoch_rewards::id() => {
+            let rewards: EpochRewards = bincode_deserialize(data, "epoch rewards sysvar")?;
+            vec![
+                field("Active", rewards.active.to_string()),
+                field(
+                    "Distribution Starting Block Height",
+                    rewards.distribution_starting_block_height.to_string(),
+                ),
+                field("Partitions", rewards.num_partitions.to_string()),
+                field("Parent Blockhash", rewards.parent_blockhash.to_string()),
+                field("Total Points", rewards.total_points.to_string()),
+                field(
+                    "Total Rewards",
+                    format!("{} SOL", lamports_to_sol(rewards.total_rewards)),
+                ),
+                field(
+                    "Distributed Rewards",
+                    format!("{} SOL", lamports_to_sol(rewards.distributed_rewards)),
+                ),
+            ]
+        }
+        id if id == sysvar::last_restart_slot::id() => {
+            let last_restart: LastRestartSlot =
+                bincode_deserialize(data, "last restart slot sysvar")?;
+            vec![field(
+                "Last Restart Slot",
+                last_restart.last_restart_slot.to_string(),
+            )]
+        }
+        id if id == sysvar::slot_hashes::id() => {
+            let slot_hashes: SlotHashes =
+                bincode_deserialize_with_limit(data.len() as u64, data, "slot hashes sysvar")?;
+            let mut fields = vec![field("Entries", slot_hashes.len().to_string())];
+            if let Some((slot, hash)) = slot_hashes.first() {
+                fields.push(field("Newest Slot", slot.to_string()));
+                fields.push(field("Newest Hash", hash.to_string()));
+            }
+            fields
+        }
+        id if id == sysvar::stake_history::id() => {
+            let stake_history: StakeHistory =
+                bincode_deserialize_with_limit(data.len() as u64, data, "stake history")?;
+            let mut fields = vec![field("Entries", stake_history.len().to_string())];
+            if let Some((epoch, entry)) = stake_history.first() {
+                fields.push(field("Latest Epoch", epoch.to_string()));
+                fields.push(field(
+                    "Effective Stake",
+                    format!("{} SOL", lamports_to_sol(entry.effective)),
+                ));
+            }
+            fields
+        }
+        id if id == sysvar::slot_history::id() => {
+            let history: SlotHistory =
+                bincode_deserialize_with_limit(data.len() as u64, data, "slot history sysvar")?;
+            vec![
+                field("Newest Slot", history.newest().to_string()),
+                field("Oldest Tracked Slot", history.oldest().to_string()),
+            ]
+        }
+        id if id == sysvar::recent_blockhashes::id() => {
+            let entries = decode_recent_blockhashes(data)?;
+            let mut fields = vec![field("Entries", entries.len().to_string())];
+            if let Some((blockhash, _)) = entries.first() {
+                fields.push(field("Newest Blockhash", blockhash.to_string()));
+            }
+            fields
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_slot_window() {
+        let mut history = SlotHistory::default();
+        for slot in [1, 2, 4, 5, 8] {
+            history.add(slot);
+        }
+
+        assert_eq!(
+            recent_slot_window(&history, 5),
+            vec![(8, true), (7, false), (6, false), (5, true), (4, true)]
+        );
+        // The window never reaches before the first tracked slot
+        assert_eq!(recent_slot_window(&history, 100).len(), 9);
+    }
+
+    #[test]
+    fn test_decode_recent_blockhashes() -> anyhow::Result<()> {
+        let entries = vec![(Hash::new_unique(), 5000u64), (Hash::new_unique(), 5000)];
+        let data = bincode::serialize(&entries)?;
+
+        assert_eq!(decode_recent_blockhashes(&data)?, entries);
+        Ok(())
+    }
+}
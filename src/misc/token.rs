@@ -0,0 +1,145 @@
+use {
+    crate::constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    anyhow::{anyhow, bail},
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::{Account as TokenAccount, AccountState, Mint},
+    },
+    spl_token_metadata_interface::state::TokenMetadata,
+};
+
+/// Returns the token program that owns `account`, if it is one of the two
+pub fn token_program_of(account: &Account) -> Option<Pubkey> {
+    [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID]
+        .into_iter()
+        .map(Pubkey::from_str_const)
+        .find(|program_id| *program_id == account.owner)
+}
+
+/// A decoded token account together with its Token-2022 extensions
+#[derive(Debug, Clone)]
+pub struct TokenAccountInfo {
+    pub account: TokenAccount,
+    pub extensions: Vec<ExtensionType>,
+}
+
+/// A decoded mint together with its Token-2022 extensions
+#[derive(Debug, Clone)]
+pub struct MintInfo {
+    pub mint: Mint,
+    pub extensions: Vec<ExtensionType>,
+    pub metadata: Option<TokenMetadata>,
+}
+
+pub fn unpack_token_account(data: &[u8]) -> anyhow::Result<TokenAccountInfo> {
+    let state = StateWithExtensions::<TokenAccount>::unpack(data)
+        .map_err(|e| anyhow!("Not a token account: {e}"))?;
+
+    Ok(TokenAccountInfo {
+        account: state.base,
+        extensions: state.get_extension_types().unwrap_or_default(),
+    })
+}
+
+pub fn unpack_mint(data: &[u8]) -> anyhow::Result<MintInfo> {
+    let state =
+        StateWithExtensions::<Mint>::unpack(data).map_err(|e| anyhow!("Not a mint: {e}"))?;
+
+    Ok(MintInfo {
+        mint: state.base,
+        extensions: state.get_extension_types().unwrap_or_default(),
+        metadata: state.get_variable_len_extension::<TokenMetadata>().ok(),
+    })
+}
+
+pub fn account_state_label(state: AccountState) -> &'static str {
+    match state {
+        AccountState::Uninitialized => "Uninitialized",
+        AccountState::Initialized => "Initialized",
+        AccountState::Frozen => "Frozen",
+    }
+}
+
+/// Formats a raw token amount using the mint's decimals, e.g. `1500000` with
+/// 6 decimals is `1.5`
+pub fn format_token_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let divisor = 10u128.pow(decimals as u32);
+    let whole = amount as u128 / divisor;
+    let fraction = amount as u128 % divisor;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{fraction:0width$}", width = decimals as usize);
+    format!("{whole}.{}", fraction.trim_end_matches('0'))
+}
+
+/// Parses a decimal token amount into raw units without going through
+/// floating point
+pub fn parse_token_amount(amount: &str, decimals: u8) -> anyhow::Result<u64> {
+    let amount = amount.trim();
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        bail!("Amount cannot be empty");
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        bail!("Invalid amount: {amount}");
+    }
+    if fraction.len() > decimals as usize {
+        bail!("Amount {amount} has more than {decimals} decimal places");
+    }
+
+    let digits = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    let raw: u64 = digits
+        .trim_start_matches('0')
+        .parse()
+        .or_else(|_| {
+            if digits.chars().all(|c| c == '0') {
+                Ok(0)
+            } else {
+                Err(())
+            }
+        })
+        .map_err(|_| anyhow!("Amount {amount} is too large"))?;
+
+    if raw == 0 {
+        bail!("Amount must be greater than zero");
+    }
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_token_amount(42, 0), "42");
+        assert_eq!(format_token_amount(1, 9), "0.000000001");
+        assert_eq!(format_token_amount(2_000_000_000, 9), "2");
+    }
+
+    #[test]
+    fn test_parse_token_amount() -> anyhow::Result<()> {
+        assert_eq!(parse_token_amount("1.5", 6)?, 1_500_000);
+        assert_eq!(parse_token_amount(".25", 2)?, 25);
+        assert_eq!(parse_token_amount("7", 0)?, 7);
+        assert!(parse_token_amount("0.0000001", 6).is_err());
+        assert!(parse_token_amount("0", 6).is_err());
+        assert!(parse_token_amount("1e3", 6).is_err());
+        assert!(parse_token_amount("99999999999999999999", 6).is_err());
+        Ok(())
+    }
+}
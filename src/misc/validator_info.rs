@@ -0,0 +1,198 @@
+use {
+    crate::{
+        constants::{
+            MAX_LONG_FIELD_LENGTH, MAX_SHORT_FIELD_LENGTH, MAX_VALIDATOR_INFO_BYTES,
+            VALIDATOR_INFO_CONFIG_ID,
+        },
+        context::ScillaContext,
+        misc::helpers::bincode_deserialize,
+    },
+    anyhow::bail,
+    serde::{Deserialize, Serialize},
+    solana_config_interface::state::{ConfigKeys, get_config_data},
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, str::FromStr},
+};
+
+pub fn validator_info_config_id() -> Pubkey {
+    Pubkey::from_str_const(VALIDATOR_INFO_CONFIG_ID)
+}
+
+/// On-chain representation of a validator-info record: a JSON string stored
+/// in a Config program account
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    pub info: String,
+}
+
+impl ValidatorInfo {
+    pub fn max_space() -> u64 {
+        MAX_VALIDATOR_INFO_BYTES
+    }
+}
+
+/// The JSON fields understood by explorers and `solana validator-info`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfoFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keybase_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+impl ValidatorInfoFields {
+    /// Checks field lengths and encodes the record, rejecting anything that
+    /// would not fit in the account
+    pub fn to_validator_info(&self) -> anyhow::Result<ValidatorInfo> {
+        let short_fields = [
+            ("Name", &self.name),
+            ("Website", &self.website),
+            ("Keybase username", &self.keybase_username),
+            ("Icon URL", &self.icon_url),
+        ];
+        for (label, value) in short_fields {
+            if let Some(value) = value
+                && value.len() > MAX_SHORT_FIELD_LENGTH
+            {
+                bail!("{label} must be at most {MAX_SHORT_FIELD_LENGTH} bytes");
+            }
+        }
+        if let Some(details) = &self.details
+            && details.len() > MAX_LONG_FIELD_LENGTH
+        {
+            bail!("Details must be at most {MAX_LONG_FIELD_LENGTH} bytes");
+        }
+
+        let info = ValidatorInfo {
+            info: serde_json::to_string(self)?,
+        };
+        if bincode::serialized_size(&info)? > ValidatorInfo::max_space() {
+            bail!(
+                "Validator info exceeds {} bytes",
+                ValidatorInfo::max_space()
+            );
+        }
+        Ok(info)
+    }
+}
+
+/// A validator-info record found on chain
+#[derive(Debug, Clone)]
+pub struct PublishedValidatorInfo {
+    pub info_pubkey: Pubkey,
+    pub identity: Pubkey,
+    pub fields: ValidatorInfoFields,
+}
+
+/// Parses Config program account data, returning the signing identity and
+/// info fields if the account holds a validator-info record
+pub fn parse_validator_info(data: &[u8]) -> anyhow::Result<Option<(Pubkey, ValidatorInfoFields)>> {
+    let config_keys: ConfigKeys = bincode_deserialize(data, "config keys")?;
+
+    let [(config_id, _), (identity, true), ..] = config_keys.keys.as_slice() else {
+        return Ok(None);
+    };
+    if *config_id != validator_info_config_id() {
+        return Ok(None);
+    }
+
+    let config_data = get_config_data(data)?;
+    let validator_info: ValidatorInfo = bincode_deserialize(config_data, "validator info")?;
+    let fields = serde_json::from_str(&validator_info.info)?;
+
+    Ok(Some((*identity, fields)))
+}
+
+/// Fetches every validator-info record published through the Config program
+pub async fn fetch_all_validator_info(
+    ctx: &ScillaContext,
+) -> anyhow::Result<Vec<PublishedValidatorInfo>> {
+    let accounts = ctx
+        .rpc()
+        .get_program_accounts(&solana_config_interface::id())
+        .await?;
+
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(info_pubkey, account)| {
+            // Skip other config records and malformed entries
+            let (identity, fields) = parse_validator_info(&account.data).ok()??;
+            Some(PublishedValidatorInfo {
+                info_pubkey,
+                identity,
+                fields,
+            })
+        })
+        .collect())
+}
+
+/// Maps validator identities to their published names. Names are only a
+/// label, so when the node refuses the `getProgramAccounts` scan (many public
+/// and paid RPCs do) this is empty rather than an error.
+pub async fn fetch_validator_names(ctx: &ScillaContext) -> HashMap<Pubkey, String> {
+    fetch_all_validator_info(ctx)
+        .await
+        .map(|infos| {
+            infos
+                .into_iter()
+                .filter_map(|info| Some((info.identity, info.fields.name?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Looks up the published name for a base58 identity, or `-` if none
+pub fn validator_name<'a>(names: &'a HashMap<Pubkey, String>, identity: &str) -> &'a str {
+    Pubkey::from_str(identity)
+        .ok()
+        .and_then(|identity| names.get(&identity))
+        .map(String::as_str)
+        .unwrap_or("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_validator_info_round_trip() -> anyhow::Result<()> {
+        let identity = Pubkey::new_unique();
+        let fields = ValidatorInfoFields {
+            name: Some("Scilla Validator".to_string()),
+            website: Some("https://example.com".to_string()),
+            ..ValidatorInfoFields::default()
+        };
+
+        let keys = ConfigKeys {
+            keys: vec![(validator_info_config_id(), false), (identity, true)],
+        };
+        let mut data = bincode::serialize(&keys)?;
+        data.extend(bincode::serialize(&fields.to_validator_info()?)?);
+
+        let (parsed_identity, parsed_fields) =
+            parse_validator_info(&data)?.expect("should be a validator info record");
+
+        assert_eq!(parsed_identity, identity);
+        assert_eq!(parsed_fields.name, fields.name);
+        assert_eq!(parsed_fields.website, fields.website);
+        assert!(parsed_fields.details.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validator_info_rejects_long_name() {
+        let fields = ValidatorInfoFields {
+            name: Some("x".repeat(MAX_SHORT_FIELD_LENGTH + 1)),
+            ..ValidatorInfoFields::default()
+        };
+        assert!(fields.to_validator_info().is_err());
+    }
+}
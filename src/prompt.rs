@@ -1,11 +1,16 @@
 use {
-    crate::commands::{
-        Command, CommandGroup, account::AccountCommand, cluster::ClusterCommand,
-        config::ConfigCommand, stake::StakeCommand, transaction::TransactionCommand,
-        vote::VoteCommand,
+    crate::{
+        commands::{
+            Command, CommandGroup, account::AccountCommand, cluster::ClusterCommand,
+            config::ConfigCommand, lookup_table::LookupTableCommand, program::ProgramCommand,
+            stake::StakeCommand, sysvar::SysvarCommand, token::TokenCommand,
+            transaction::TransactionCommand, vote::VoteCommand,
+        },
+        misc::helpers::read_keypair_from_path,
     },
     inquire::{Select, Text},
-    std::str::FromStr,
+    solana_keypair::Keypair,
+    std::{path::PathBuf, str::FromStr},
 };
 pub fn prompt_for_command() -> anyhow::Result<Command> {
     let top_level = Select::new(
@@ -13,9 +18,13 @@ pub fn prompt_for_command() -> anyhow::Result<Command> {
         vec![
             CommandGroup::Account,
             CommandGroup::Cluster,
+            CommandGroup::Sysvar,
             CommandGroup::Stake,
             CommandGroup::Vote,
             CommandGroup::Transaction,
+            CommandGroup::Token,
+            CommandGroup::Program,
+            CommandGroup::LookupTable,
             CommandGroup::ScillaConfig,
             CommandGroup::Exit,
         ],
@@ -24,11 +33,15 @@ pub fn prompt_for_command() -> anyhow::Result<Command> {
 
     let command = match top_level {
         CommandGroup::Cluster => Command::Cluster(prompt_cluster()?),
+        CommandGroup::Sysvar => Command::Sysvar(prompt_sysvar()?),
         CommandGroup::Stake => Command::Stake(prompt_stake()?),
         CommandGroup::Account => Command::Account(prompt_account()?),
         CommandGroup::Vote => Command::Vote(prompt_vote()?),
         CommandGroup::ScillaConfig => Command::ScillaConfig(prompt_config()?),
         CommandGroup::Transaction => Command::Transaction(prompt_transaction()?),
+        CommandGroup::Token => Command::Token(prompt_token()?),
+        CommandGroup::Program => Command::Program(prompt_program()?),
+        CommandGroup::LookupTable => Command::LookupTable(prompt_lookup_table()?),
         CommandGroup::Exit => Command::Exit,
     };
 
@@ -43,10 +56,17 @@ fn prompt_cluster() -> anyhow::Result<ClusterCommand> {
             ClusterCommand::CurrentSlot,
             ClusterCommand::BlockHeight,
             ClusterCommand::BlockTime,
+            ClusterCommand::Block,
             ClusterCommand::Validators,
+            ClusterCommand::ClusterNodes,
             ClusterCommand::ClusterVersion,
+            ClusterCommand::Health,
             ClusterCommand::SupplyInfo,
             ClusterCommand::Inflation,
+            ClusterCommand::InflationRewards,
+            ClusterCommand::LeaderSchedule,
+            ClusterCommand::BlockProduction,
+            ClusterCommand::Dashboard,
             ClusterCommand::GoBack,
         ],
     )
@@ -67,6 +87,7 @@ fn prompt_stake() -> anyhow::Result<StakeCommand> {
             StakeCommand::Split,
             StakeCommand::Show,
             StakeCommand::History,
+            StakeCommand::Forecast,
             StakeCommand::GoBack,
         ],
     )
@@ -85,6 +106,7 @@ fn prompt_account() -> anyhow::Result<AccountCommand> {
             AccountCommand::Airdrop,
             AccountCommand::LargestAccounts,
             AccountCommand::NonceAccount,
+            AccountCommand::Watch,
             AccountCommand::GoBack,
         ],
     )
@@ -99,8 +121,14 @@ fn prompt_vote() -> anyhow::Result<VoteCommand> {
         vec![
             VoteCommand::CreateVoteAccount,
             VoteCommand::AuthorizeVoter,
+            VoteCommand::AuthorizeWithdrawer,
+            VoteCommand::UpdateCommission,
+            VoteCommand::UpdateValidatorIdentity,
             VoteCommand::WithdrawFromVoteAccount,
             VoteCommand::ShowVoteAccount,
+            VoteCommand::VoteAccountHealth,
+            VoteCommand::PublishValidatorInfo,
+            VoteCommand::SearchValidatorInfo,
             VoteCommand::CloseVoteAccount,
             VoteCommand::GoBack,
         ],
@@ -117,7 +145,10 @@ fn prompt_transaction() -> anyhow::Result<TransactionCommand> {
             TransactionCommand::CheckConfirmation,
             TransactionCommand::FetchStatus,
             TransactionCommand::FetchTransaction,
+            TransactionCommand::AddressHistory,
+            TransactionCommand::DecodeTransaction,
             TransactionCommand::SendTransaction,
+            TransactionCommand::RunBatchPlan,
             TransactionCommand::GoBack,
         ],
     )
@@ -126,6 +157,84 @@ fn prompt_transaction() -> anyhow::Result<TransactionCommand> {
     Ok(choice)
 }
 
+fn prompt_sysvar() -> anyhow::Result<SysvarCommand> {
+    let choice = Select::new(
+        "Sysvar Command:",
+        vec![
+            SysvarCommand::Clock,
+            SysvarCommand::EpochSchedule,
+            SysvarCommand::Rent,
+            SysvarCommand::EpochRewards,
+            SysvarCommand::SlotHashes,
+            SysvarCommand::SlotHistory,
+            SysvarCommand::RecentBlockhashes,
+            SysvarCommand::LastRestartSlot,
+            SysvarCommand::StakeHistory,
+            SysvarCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_token() -> anyhow::Result<TokenCommand> {
+    let choice = Select::new(
+        "Token Command:",
+        vec![
+            TokenCommand::ListAccounts,
+            TokenCommand::ShowMint,
+            TokenCommand::CreateAssociatedAccount,
+            TokenCommand::Transfer,
+            TokenCommand::CloseAccount,
+            TokenCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_program() -> anyhow::Result<ProgramCommand> {
+    let choice = Select::new(
+        "Program Command:",
+        vec![
+            ProgramCommand::Show,
+            ProgramCommand::Dump,
+            ProgramCommand::Deploy,
+            ProgramCommand::WriteBuffer,
+            ProgramCommand::Upgrade,
+            ProgramCommand::SetUpgradeAuthority,
+            ProgramCommand::Extend,
+            ProgramCommand::ListBuffers,
+            ProgramCommand::CloseBuffers,
+            ProgramCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_lookup_table() -> anyhow::Result<LookupTableCommand> {
+    let choice = Select::new(
+        "Lookup Table Command:",
+        vec![
+            LookupTableCommand::Create,
+            LookupTableCommand::Extend,
+            LookupTableCommand::Freeze,
+            LookupTableCommand::Deactivate,
+            LookupTableCommand::Close,
+            LookupTableCommand::Show,
+            LookupTableCommand::UseForTransactions,
+            LookupTableCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
 fn prompt_config() -> anyhow::Result<ConfigCommand> {
     let choice = Select::new(
         "ScillaConfig Command:",
@@ -156,3 +265,34 @@ where
         }
     }
 }
+
+/// Like [`prompt_data`], but an empty answer yields `None` instead of a
+/// parse error
+pub fn prompt_optional_data<T>(msg: &str) -> anyhow::Result<Option<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ToString + Send + Sync + 'static,
+{
+    loop {
+        let input = Text::new(msg).prompt()?;
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        match T::from_str(trimmed) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => {
+                eprintln!("Invalid input: {}. Please try again.\n", e.to_string());
+            }
+        }
+    }
+}
+
+/// Keypair that signs as an account's authority; `None` means the configured
+/// wallet
+pub fn prompt_authority_keypair() -> anyhow::Result<Option<Keypair>> {
+    let path: Option<PathBuf> = prompt_optional_data(
+        "Enter Authority Keypair Path (leave empty for the configured keypair):",
+    )?;
+    path.map(read_keypair_from_path).transpose()
+}
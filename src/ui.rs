@@ -1,12 +1,9 @@
 use {
-    console::style,
+    console::{Term, style},
     indicatif::{ProgressBar, ProgressStyle},
 };
 
-pub async fn show_spinner<F, T>(message: &str, fut: F) -> anyhow::Result<T>
-where
-    F: std::future::Future<Output = anyhow::Result<T>>,
-{
+pub fn new_spinner(message: &str) -> ProgressBar {
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::with_template("{spinner:.cyan} {msg}")
@@ -15,6 +12,14 @@ where
     );
     spinner.enable_steady_tick(std::time::Duration::from_millis(100));
     spinner.set_message(message.to_string());
+    spinner
+}
+
+pub async fn show_spinner<F, T>(message: &str, fut: F) -> anyhow::Result<T>
+where
+    F: std::future::Future<Output = anyhow::Result<T>>,
+{
+    let spinner = new_spinner(message);
 
     let result = fut.await;
     spinner.finish_with_message("✅ Done");
@@ -25,3 +30,27 @@ where
 pub fn print_error(message: impl std::fmt::Display) {
     println!("\n{}\n", style(message).red().bold());
 }
+
+/// A `width`-cell bar filled to `fraction` (0.0 to 1.0)
+pub fn progress_bar(fraction: f64, width: usize) -> String {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Hides the terminal cursor until dropped, so it comes back however a
+/// full-screen view exits
+pub struct HiddenCursor(Term);
+
+impl HiddenCursor {
+    pub fn new() -> std::io::Result<Self> {
+        let term = Term::stdout();
+        term.hide_cursor()?;
+        Ok(Self(term))
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        let _ = self.0.show_cursor();
+    }
+}